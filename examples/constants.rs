@@ -63,8 +63,8 @@ fn main() {
 
     // Special values
     println!("\n--- Special Values ---");
-    println!("INFINITY = {}", INFINITY);
-    println!("EPSILON  = {}", EPSILON);
+    println!("EXT_INFINITY = {}", EXT_INFINITY);
+    println!("EPSILON      = {}", EPSILON);
 
     // Using constants in calculations
     println!("\n--- Using Constants in Calculations ---");
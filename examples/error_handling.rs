@@ -77,13 +77,13 @@ fn main() {
     // Saturating operations (never fail)
     println!("\n--- Saturating Operations ---");
 
-    #[cfg(not(feature = "non-zero"))]
-    {
-        let small = pos_or_panic!(5.0);
-        let large = pos_or_panic!(100.0);
-        let result = small.saturating_sub(&large);
-        println!("{small}.saturating_sub({large}) = {result} (saturates to ZERO)");
-    }
+    let small = pos_or_panic!(5.0);
+    let large = pos_or_panic!(100.0);
+    let result = small.saturating_sub(&large);
+    println!("{small}.saturating_sub({large}) = {result} (saturates to MIN)");
+
+    let result = MAX.saturating_add(&large);
+    println!("MAX.saturating_add({large}) = {result} (saturates to MAX)");
 
     // Using Result combinators
     println!("\n--- Using Result Combinators ---");
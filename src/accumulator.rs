@@ -0,0 +1,305 @@
+/******************************************************************************
+   Author: Joaquín Béjar García
+   Email: jb@taunais.com
+   Date: 18/10/26
+******************************************************************************/
+
+//! Overflow-aware accumulation of `Positive` values.
+//!
+//! This module provides [`PositiveAccumulator`], a running sum that reports
+//! `Decimal` overflow as a [`PositiveError`] instead of panicking, and the
+//! [`PositiveIteratorExt`] extension trait that exposes it on iterators.
+//! The `Sum` impls for `Positive` are built on top of it; products have no
+//! running count to keep, so `try_product` and the `Product` impls multiply
+//! with checked `Decimal` arithmetic directly.
+
+use crate::Positive;
+use crate::error::{PositiveError, PositiveErrorKind, PositiveResult};
+use crate::positive::positive_result;
+use rust_decimal::Decimal;
+use std::borrow::Borrow;
+use std::iter::{Product, Sum};
+
+/// A running sum of `Positive` values that also tracks how many were added.
+///
+/// Unlike folding with `+`, pushing a value never panics: an overflowing
+/// addition is reported as `PositiveError::ArithmeticError` and leaves the
+/// accumulator unchanged.
+///
+/// # Example
+///
+/// ```rust
+/// use positive::{PositiveAccumulator, pos_or_panic};
+///
+/// let mut acc = PositiveAccumulator::new();
+/// acc.push(pos_or_panic!(2.0)).unwrap();
+/// acc.push(pos_or_panic!(4.0)).unwrap();
+///
+/// assert_eq!(acc.count(), 2);
+/// assert_eq!(acc.sum().unwrap(), pos_or_panic!(6.0));
+/// assert_eq!(acc.mean().unwrap(), pos_or_panic!(3.0));
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct PositiveAccumulator {
+    sum: Decimal,
+    count: usize,
+}
+
+impl PositiveAccumulator {
+    /// Creates an empty accumulator.
+    #[must_use]
+    pub const fn new() -> Self {
+        PositiveAccumulator {
+            sum: Decimal::ZERO,
+            count: 0,
+        }
+    }
+
+    /// Adds a value to the running sum.
+    ///
+    /// # Errors
+    ///
+    /// Returns `PositiveError::ArithmeticError` if the sum would overflow
+    /// `Decimal`. The accumulator is left unchanged in that case.
+    pub fn push(&mut self, value: Positive) -> PositiveResult<()> {
+        self.sum = self.sum.checked_add(value.into_inner()).ok_or_else(|| {
+            PositiveError::arithmetic(
+                "sum",
+                PositiveErrorKind::Overflow,
+                None,
+                "result overflowed or is undefined",
+            )
+        })?;
+        self.count += 1;
+        Ok(())
+    }
+
+    /// Returns the number of values pushed so far.
+    #[must_use]
+    pub const fn count(&self) -> usize {
+        self.count
+    }
+
+    /// Returns `true` if no value has been pushed yet.
+    #[must_use]
+    pub const fn is_empty(&self) -> bool {
+        self.count == 0
+    }
+
+    /// Returns the sum of the values pushed so far.
+    ///
+    /// # Errors
+    ///
+    /// With the `non-zero` feature enabled, an empty accumulator has no valid
+    /// sum and returns `PositiveError::ArithmeticError`. Without it, the sum of
+    /// no values is zero.
+    pub fn sum(&self) -> PositiveResult<Positive> {
+        positive_result("sum", Some(self.sum))
+    }
+
+    /// Returns the arithmetic mean of the values pushed so far.
+    ///
+    /// # Errors
+    ///
+    /// Returns `PositiveError::ArithmeticError` if the accumulator is empty.
+    pub fn mean(&self) -> PositiveResult<Positive> {
+        if self.is_empty() {
+            return Err(PositiveError::arithmetic(
+                "mean",
+                PositiveErrorKind::DivisionByZero,
+                None,
+                "cannot compute the mean of an empty input",
+            ));
+        }
+        positive_result("mean", self.sum.checked_div(Decimal::from(self.count)))
+    }
+}
+
+/// Overflow-checked reductions over iterators of `Positive` values.
+///
+/// Implemented for every iterator whose items borrow as `Positive`, so it
+/// works with both `iter()` and `into_iter()`.
+///
+/// # Example
+///
+/// ```rust
+/// use positive::{PositiveIteratorExt, pos_or_panic};
+///
+/// let values = [pos_or_panic!(1.5), pos_or_panic!(2.5)];
+/// assert_eq!(values.iter().try_sum().unwrap(), pos_or_panic!(4.0));
+/// assert_eq!(values.iter().checked_sum(), Some(pos_or_panic!(4.0)));
+/// assert_eq!(values.iter().try_mean().unwrap(), pos_or_panic!(2.0));
+/// ```
+pub trait PositiveIteratorExt: Iterator + Sized
+where
+    Self::Item: Borrow<Positive>,
+{
+    /// Feeds every item into a [`PositiveAccumulator`].
+    ///
+    /// # Errors
+    ///
+    /// Returns `PositiveError::ArithmeticError` as soon as the running sum
+    /// overflows `Decimal`.
+    fn accumulate(self) -> PositiveResult<PositiveAccumulator> {
+        let mut accumulator = PositiveAccumulator::new();
+        for value in self {
+            accumulator.push(*value.borrow())?;
+        }
+        Ok(accumulator)
+    }
+
+    /// Sums the items, reporting overflow as an error.
+    ///
+    /// # Errors
+    ///
+    /// Returns `PositiveError::ArithmeticError` if the sum overflows `Decimal`,
+    /// or if the iterator is empty when the `non-zero` feature is enabled.
+    fn try_sum(self) -> PositiveResult<Positive> {
+        self.accumulate()?.sum()
+    }
+
+    /// Sums the items, returning `None` where [`try_sum`](Self::try_sum) would fail.
+    fn checked_sum(self) -> Option<Positive> {
+        self.try_sum().ok()
+    }
+
+    /// Computes the arithmetic mean of the items.
+    ///
+    /// # Errors
+    ///
+    /// Returns `PositiveError::ArithmeticError` if the iterator is empty or the
+    /// sum overflows `Decimal`.
+    fn try_mean(self) -> PositiveResult<Positive> {
+        self.accumulate()?.mean()
+    }
+
+    /// Multiplies the items, reporting overflow as an error.
+    ///
+    /// The product of an empty iterator is one.
+    ///
+    /// # Errors
+    ///
+    /// Returns `PositiveError::ArithmeticError` as soon as the running product
+    /// overflows `Decimal`, or underflows to zero when the `non-zero` feature
+    /// is enabled.
+    fn try_product(self) -> PositiveResult<Positive> {
+        let mut product = Positive::ONE;
+        for value in self {
+            product = positive_result(
+                "product",
+                product
+                    .into_inner()
+                    .checked_mul(value.borrow().into_inner()),
+            )?;
+        }
+        Ok(product)
+    }
+
+    /// Multiplies the items, returning `None` where
+    /// [`try_product`](Self::try_product) would fail.
+    fn checked_product(self) -> Option<Positive> {
+        self.try_product().ok()
+    }
+}
+
+impl<I> PositiveIteratorExt for I
+where
+    I: Iterator,
+    I::Item: Borrow<Positive>,
+{
+}
+
+/// Sums `Positive` values.
+///
+/// # Panics
+///
+/// Panics with the `PositiveError` message if the sum overflows `Decimal`.
+/// Use [`PositiveIteratorExt::try_sum`] to handle overflow without panicking.
+///
+/// This impl is not available when the `non-zero` feature is enabled because
+/// the sum of an empty iterator would be zero; collect into
+/// `PositiveResult<Positive>` or `Option<Positive>` instead.
+#[cfg(not(feature = "non-zero"))]
+impl Sum for Positive {
+    fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+        iter.try_sum().unwrap_or_else(|e| panic!("{e}"))
+    }
+}
+
+/// Sums `&Positive` values. See the `Sum<Positive>` impl for details.
+#[cfg(not(feature = "non-zero"))]
+impl<'a> Sum<&'a Positive> for Positive {
+    fn sum<I: Iterator<Item = &'a Positive>>(iter: I) -> Self {
+        iter.try_sum().unwrap_or_else(|e| panic!("{e}"))
+    }
+}
+
+impl Sum<Positive> for PositiveResult<Positive> {
+    fn sum<I: Iterator<Item = Positive>>(iter: I) -> Self {
+        iter.try_sum()
+    }
+}
+
+impl<'a> Sum<&'a Positive> for PositiveResult<Positive> {
+    fn sum<I: Iterator<Item = &'a Positive>>(iter: I) -> Self {
+        iter.try_sum()
+    }
+}
+
+impl Sum<Positive> for Option<Positive> {
+    fn sum<I: Iterator<Item = Positive>>(iter: I) -> Self {
+        iter.checked_sum()
+    }
+}
+
+impl<'a> Sum<&'a Positive> for Option<Positive> {
+    fn sum<I: Iterator<Item = &'a Positive>>(iter: I) -> Self {
+        iter.checked_sum()
+    }
+}
+
+/// Multiplies `Positive` values.
+///
+/// The product of an empty iterator is one, so this impl is available under
+/// both feature sets.
+///
+/// # Panics
+///
+/// Panics with the `PositiveError` message if the product overflows `Decimal`.
+/// Use [`PositiveIteratorExt::try_product`] to handle overflow without panicking.
+impl Product for Positive {
+    fn product<I: Iterator<Item = Self>>(iter: I) -> Self {
+        iter.try_product().unwrap_or_else(|e| panic!("{e}"))
+    }
+}
+
+/// Multiplies `&Positive` values. See the `Product<Positive>` impl for details.
+impl<'a> Product<&'a Positive> for Positive {
+    fn product<I: Iterator<Item = &'a Positive>>(iter: I) -> Self {
+        iter.try_product().unwrap_or_else(|e| panic!("{e}"))
+    }
+}
+
+impl Product<Positive> for PositiveResult<Positive> {
+    fn product<I: Iterator<Item = Positive>>(iter: I) -> Self {
+        iter.try_product()
+    }
+}
+
+impl<'a> Product<&'a Positive> for PositiveResult<Positive> {
+    fn product<I: Iterator<Item = &'a Positive>>(iter: I) -> Self {
+        iter.try_product()
+    }
+}
+
+impl Product<Positive> for Option<Positive> {
+    fn product<I: Iterator<Item = Positive>>(iter: I) -> Self {
+        iter.checked_product()
+    }
+}
+
+impl<'a> Product<&'a Positive> for Option<Positive> {
+    fn product<I: Iterator<Item = &'a Positive>>(iter: I) -> Self {
+        iter.checked_product()
+    }
+}
@@ -0,0 +1,231 @@
+/******************************************************************************
+   Author: Joaquín Béjar García
+   Email: jb@taunais.com
+   Date: 18/10/26
+******************************************************************************/
+
+//! Compact, SI and engineering notation for `Positive`.
+//!
+//! Each mode rounds the value to a number of significant digits, scales it
+//! by a power of ten and appends a suffix for that power: `1.2K`, `3.45M`,
+//! `500µ` or `12.5e6`. Trailing zeros are dropped and rounding is half to
+//! even, like [`Positive::round_to`].
+//!
+//! The magnitude comes from the `Decimal` digits, as in
+//! [`Positive::round_to_nice_number`], so the output never goes through
+//! `f64`.
+
+use crate::Positive;
+use crate::error::{PositiveError, PositiveResult};
+use crate::format::magnitude;
+use crate::positive::{MAX_SCALE, round_significant, shift};
+use rust_decimal::Decimal;
+
+/// A table of suffixes keyed by the power of ten they stand for.
+///
+/// A value is printed with the suffix of the largest exponent not above its
+/// magnitude. Exponent 0 has an empty suffix unless the table sets one;
+/// values below every exponent use the smallest one.
+///
+/// # Example
+///
+/// ```rust
+/// use positive::{SuffixTable, pos_or_panic};
+///
+/// let bytes = SuffixTable::new([(3, " kB"), (6, " MB"), (9, " GB")]).unwrap();
+/// assert_eq!(pos_or_panic!(1536000.0).format_with_suffixes(3, &bytes), "1.54 MB");
+/// assert_eq!(pos_or_panic!(512.0).format_with_suffixes(3, &bytes), "512");
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SuffixTable {
+    entries: Vec<(i32, String)>,
+}
+
+impl SuffixTable {
+    /// Creates a table from `(exponent, suffix)` pairs in any order. A later
+    /// pair replaces an earlier one with the same exponent.
+    ///
+    /// # Errors
+    ///
+    /// Returns `PositiveError::InvalidPrecision` for an exponent outside
+    /// `-28..=28`, the range of `Decimal`.
+    pub fn new<S: Into<String>>(
+        entries: impl IntoIterator<Item = (i32, S)>,
+    ) -> PositiveResult<Self> {
+        let mut table: Vec<(i32, String)> = Vec::new();
+        for (exponent, suffix) in entries {
+            if exponent.unsigned_abs() > MAX_SCALE {
+                return Err(PositiveError::invalid_precision(
+                    exponent,
+                    "suffix exponents must be between -28 and 28",
+                ));
+            }
+            table.retain(|(existing, _)| *existing != exponent);
+            table.push((exponent, suffix.into()));
+        }
+        if !table.iter().any(|(exponent, _)| *exponent == 0) {
+            table.push((0, String::new()));
+        }
+        table.sort_by_key(|(exponent, _)| *exponent);
+        Ok(SuffixTable { entries: table })
+    }
+
+    /// `K`, `M`, `B` and `T` for thousands to trillions.
+    #[must_use]
+    pub fn short_scale() -> Self {
+        Self::from_static(&[(3, "K"), (6, "M"), (9, "B"), (12, "T")])
+    }
+
+    /// `k`, `m`, `bn` and `tn`, as used in financial reporting.
+    #[must_use]
+    pub fn financial() -> Self {
+        Self::from_static(&[(3, "k"), (6, "m"), (9, "bn"), (12, "tn")])
+    }
+
+    /// SI prefixes from pico (`p`) to exa (`E`).
+    #[must_use]
+    pub fn si() -> Self {
+        Self::from_static(&[
+            (-12, "p"),
+            (-9, "n"),
+            (-6, "µ"),
+            (-3, "m"),
+            (3, "k"),
+            (6, "M"),
+            (9, "G"),
+            (12, "T"),
+            (15, "P"),
+            (18, "E"),
+        ])
+    }
+
+    fn from_static(entries: &[(i32, &str)]) -> Self {
+        Self::new(entries.iter().copied()).expect("built-in suffix tables are valid")
+    }
+
+    /// The exponent and suffix used for a value of the given magnitude.
+    fn select(&self, magnitude: i32) -> (i32, &str) {
+        let (exponent, suffix) = self
+            .entries
+            .iter()
+            .rev()
+            .find(|(exponent, _)| *exponent <= magnitude)
+            .unwrap_or(&self.entries[0]);
+        (*exponent, suffix)
+    }
+}
+
+/// Rounds `value` to `significant_digits` and scales it by the exponent
+/// `select` picks for its magnitude.
+fn scaled(value: Decimal, significant_digits: u32, select: impl Fn(i32) -> i32) -> (Decimal, i32) {
+    let Some(mut current) = magnitude(value) else {
+        return (Decimal::ZERO, 0);
+    };
+    let digits = significant_digits.clamp(1, MAX_SCALE);
+    loop {
+        let exponent = select(current);
+        // The exponent is picked from the magnitude, so the shifted value is
+        // small; engineering exponents just past the `Decimal` range, like
+        // `-30` for `1e-28`, still fit.
+        let shifted = shift(value, -i64::from(exponent))
+            .expect("a value scaled to its suffix fits in a Decimal");
+        let rounded = round_significant(shifted, digits)
+            .unwrap_or(shifted)
+            .normalize();
+        // Rounding up may carry into the next power of ten, e.g. 999.96 to
+        // 1000, which can call for a larger suffix.
+        match magnitude(rounded) {
+            Some(carried) if carried + exponent > current => current = carried + exponent,
+            _ => return (rounded, exponent),
+        }
+    }
+}
+
+impl Positive {
+    /// Formats the value with `K`, `M`, `B` and `T` suffixes.
+    ///
+    /// The value is rounded half to even to `significant_digits` (at least
+    /// one) and printed without trailing zeros.
+    ///
+    /// ```rust
+    /// use positive::pos_or_panic;
+    ///
+    /// assert_eq!(pos_or_panic!(1234.0).format_compact(2), "1.2K");
+    /// assert_eq!(pos_or_panic!(3450000.0).format_compact(3), "3.45M");
+    /// assert_eq!(pos_or_panic!(999960.0).format_compact(4), "1M");
+    /// assert_eq!(pos_or_panic!(0.5).format_compact(3), "0.5");
+    /// ```
+    #[must_use]
+    pub fn format_compact(&self, significant_digits: u32) -> String {
+        self.format_with_suffixes(significant_digits, &SuffixTable::short_scale())
+    }
+
+    /// Formats the value with SI prefixes, from `p` to `E`.
+    ///
+    /// Values that round to less than `1p` fall back to
+    /// [`Positive::format_engineering`] instead of printing leading zeros.
+    ///
+    /// ```rust
+    /// use positive::pos_or_panic;
+    ///
+    /// assert_eq!(pos_or_panic!(0.00047).format_si(3), "470µ");
+    /// assert_eq!(pos_or_panic!(2500.0).format_si(3), "2.5k");
+    /// assert_eq!(pos_or_panic!(0.0000000000000012).format_si(3), "1.2e-15");
+    /// ```
+    #[must_use]
+    pub fn format_si(&self, significant_digits: u32) -> String {
+        let table = SuffixTable::si();
+        let (digits, suffix) = self.suffixed(significant_digits, &table);
+        if digits.is_zero() {
+            return digits.to_string();
+        }
+        if digits < Decimal::ONE {
+            return self.format_engineering(significant_digits);
+        }
+        format!("{digits}{suffix}")
+    }
+
+    /// Formats the value in engineering notation: scientific notation with
+    /// an exponent that is a multiple of three.
+    ///
+    /// ```rust
+    /// use positive::pos_or_panic;
+    ///
+    /// assert_eq!(pos_or_panic!(12500000.0).format_engineering(3), "12.5e6");
+    /// assert_eq!(pos_or_panic!(0.0125).format_engineering(3), "12.5e-3");
+    /// assert_eq!(pos_or_panic!(7.0).format_engineering(3), "7e0");
+    /// ```
+    #[must_use]
+    pub fn format_engineering(&self, significant_digits: u32) -> String {
+        let (digits, exponent) = scaled(*self.as_decimal(), significant_digits, |magnitude| {
+            magnitude.div_euclid(3) * 3
+        });
+        format!("{digits}e{exponent}")
+    }
+
+    /// Formats the value with the suffixes of `table`.
+    ///
+    /// ```rust
+    /// use positive::{SuffixTable, pos_or_panic};
+    ///
+    /// let value = pos_or_panic!(12500000000.0);
+    /// assert_eq!(value.format_with_suffixes(3, &SuffixTable::financial()), "12.5bn");
+    /// ```
+    #[must_use]
+    pub fn format_with_suffixes(&self, significant_digits: u32, table: &SuffixTable) -> String {
+        let (digits, suffix) = self.suffixed(significant_digits, table);
+        if digits.is_zero() {
+            return digits.to_string();
+        }
+        format!("{digits}{suffix}")
+    }
+
+    /// The rounded, scaled digits and the suffix of `table` that goes with
+    /// them.
+    fn suffixed<'a>(&self, significant_digits: u32, table: &'a SuffixTable) -> (Decimal, &'a str) {
+        let (digits, exponent) = scaled(*self.as_decimal(), significant_digits, |magnitude| {
+            table.select(magnitude).0
+        });
+        (digits, table.select(exponent).1)
+    }
+}
@@ -9,7 +9,7 @@
 //! This module provides commonly used numeric constants as `Positive` values,
 //! including integers, mathematical constants, and special values.
 
-use crate::Positive;
+use crate::{Positive, PositiveExt};
 use rust_decimal::Decimal;
 use rust_decimal_macros::dec;
 
@@ -21,157 +21,157 @@ use rust_decimal_macros::dec;
 ///
 /// This constant is not available when the `non-zero` feature is enabled.
 #[cfg(not(feature = "non-zero"))]
-pub const ZERO: Positive = Positive(Decimal::ZERO);
+pub const ZERO: Positive = Positive::new_const(Decimal::ZERO);
 
 /// A value of one represented as a `Positive` value.
-pub const ONE: Positive = Positive(Decimal::ONE);
+pub const ONE: Positive = Positive::new_const(Decimal::ONE);
 
 /// A value of two represented as a `Positive` value.
-pub const TWO: Positive = Positive(Decimal::TWO);
+pub const TWO: Positive = Positive::new_const(Decimal::TWO);
 
 /// A value of three represented as a `Positive` value.
-pub const THREE: Positive = Positive(dec!(3));
+pub const THREE: Positive = Positive::new_const(dec!(3));
 
 /// A value of four represented as a `Positive` value.
-pub const FOUR: Positive = Positive(dec!(4));
+pub const FOUR: Positive = Positive::new_const(dec!(4));
 
 /// A value of five represented as a `Positive` value.
-pub const FIVE: Positive = Positive(dec!(5));
+pub const FIVE: Positive = Positive::new_const(dec!(5));
 
 /// A value of six represented as a `Positive` value.
-pub const SIX: Positive = Positive(dec!(6));
+pub const SIX: Positive = Positive::new_const(dec!(6));
 
 /// A value of seven represented as a `Positive` value.
-pub const SEVEN: Positive = Positive(dec!(7));
+pub const SEVEN: Positive = Positive::new_const(dec!(7));
 
 /// A value of eight represented as a `Positive` value.
-pub const EIGHT: Positive = Positive(dec!(8));
+pub const EIGHT: Positive = Positive::new_const(dec!(8));
 
 /// A value of nine represented as a `Positive` value.
-pub const NINE: Positive = Positive(dec!(9));
+pub const NINE: Positive = Positive::new_const(dec!(9));
 
 /// A value of ten represented as a `Positive` value.
-pub const TEN: Positive = Positive(Decimal::TEN);
+pub const TEN: Positive = Positive::new_const(Decimal::TEN);
 
 // =============================================================================
 // Multiples of 5 (15-95)
 // =============================================================================
 
 /// A value of fifteen represented as a `Positive` value.
-pub const FIFTEEN: Positive = Positive(dec!(15));
+pub const FIFTEEN: Positive = Positive::new_const(dec!(15));
 
 /// A value of twenty represented as a `Positive` value.
-pub const TWENTY: Positive = Positive(dec!(20));
+pub const TWENTY: Positive = Positive::new_const(dec!(20));
 
 /// A value of twenty-five represented as a `Positive` value.
-pub const TWENTY_FIVE: Positive = Positive(dec!(25));
+pub const TWENTY_FIVE: Positive = Positive::new_const(dec!(25));
 
 /// A value of thirty represented as a `Positive` value.
-pub const THIRTY: Positive = Positive(dec!(30));
+pub const THIRTY: Positive = Positive::new_const(dec!(30));
 
 /// A value of thirty-five represented as a `Positive` value.
-pub const THIRTY_FIVE: Positive = Positive(dec!(35));
+pub const THIRTY_FIVE: Positive = Positive::new_const(dec!(35));
 
 /// A value of forty represented as a `Positive` value.
-pub const FORTY: Positive = Positive(dec!(40));
+pub const FORTY: Positive = Positive::new_const(dec!(40));
 
 /// A value of forty-five represented as a `Positive` value.
-pub const FORTY_FIVE: Positive = Positive(dec!(45));
+pub const FORTY_FIVE: Positive = Positive::new_const(dec!(45));
 
 /// A value of fifty represented as a `Positive` value.
-pub const FIFTY: Positive = Positive(dec!(50));
+pub const FIFTY: Positive = Positive::new_const(dec!(50));
 
 /// A value of fifty-five represented as a `Positive` value.
-pub const FIFTY_FIVE: Positive = Positive(dec!(55));
+pub const FIFTY_FIVE: Positive = Positive::new_const(dec!(55));
 
 /// A value of sixty represented as a `Positive` value.
-pub const SIXTY: Positive = Positive(dec!(60));
+pub const SIXTY: Positive = Positive::new_const(dec!(60));
 
 /// A value of sixty-five represented as a `Positive` value.
-pub const SIXTY_FIVE: Positive = Positive(dec!(65));
+pub const SIXTY_FIVE: Positive = Positive::new_const(dec!(65));
 
 /// A value of seventy represented as a `Positive` value.
-pub const SEVENTY: Positive = Positive(dec!(70));
+pub const SEVENTY: Positive = Positive::new_const(dec!(70));
 
 /// A value of seventy-five represented as a `Positive` value.
-pub const SEVENTY_FIVE: Positive = Positive(dec!(75));
+pub const SEVENTY_FIVE: Positive = Positive::new_const(dec!(75));
 
 /// A value of eighty represented as a `Positive` value.
-pub const EIGHTY: Positive = Positive(dec!(80));
+pub const EIGHTY: Positive = Positive::new_const(dec!(80));
 
 /// A value of eighty-five represented as a `Positive` value.
-pub const EIGHTY_FIVE: Positive = Positive(dec!(85));
+pub const EIGHTY_FIVE: Positive = Positive::new_const(dec!(85));
 
 /// A value of ninety represented as a `Positive` value.
-pub const NINETY: Positive = Positive(dec!(90));
+pub const NINETY: Positive = Positive::new_const(dec!(90));
 
 /// A value of ninety-five represented as a `Positive` value.
-pub const NINETY_FIVE: Positive = Positive(dec!(95));
+pub const NINETY_FIVE: Positive = Positive::new_const(dec!(95));
 
 // =============================================================================
 // Multiples of 100 (100-900)
 // =============================================================================
 
 /// A value of one hundred represented as a `Positive` value.
-pub const HUNDRED: Positive = Positive(Decimal::ONE_HUNDRED);
+pub const HUNDRED: Positive = Positive::new_const(Decimal::ONE_HUNDRED);
 
 /// A value of two hundred represented as a `Positive` value.
-pub const TWO_HUNDRED: Positive = Positive(dec!(200));
+pub const TWO_HUNDRED: Positive = Positive::new_const(dec!(200));
 
 /// A value of three hundred represented as a `Positive` value.
-pub const THREE_HUNDRED: Positive = Positive(dec!(300));
+pub const THREE_HUNDRED: Positive = Positive::new_const(dec!(300));
 
 /// A value of four hundred represented as a `Positive` value.
-pub const FOUR_HUNDRED: Positive = Positive(dec!(400));
+pub const FOUR_HUNDRED: Positive = Positive::new_const(dec!(400));
 
 /// A value of five hundred represented as a `Positive` value.
-pub const FIVE_HUNDRED: Positive = Positive(dec!(500));
+pub const FIVE_HUNDRED: Positive = Positive::new_const(dec!(500));
 
 /// A value of six hundred represented as a `Positive` value.
-pub const SIX_HUNDRED: Positive = Positive(dec!(600));
+pub const SIX_HUNDRED: Positive = Positive::new_const(dec!(600));
 
 /// A value of seven hundred represented as a `Positive` value.
-pub const SEVEN_HUNDRED: Positive = Positive(dec!(700));
+pub const SEVEN_HUNDRED: Positive = Positive::new_const(dec!(700));
 
 /// A value of eight hundred represented as a `Positive` value.
-pub const EIGHT_HUNDRED: Positive = Positive(dec!(800));
+pub const EIGHT_HUNDRED: Positive = Positive::new_const(dec!(800));
 
 /// A value of nine hundred represented as a `Positive` value.
-pub const NINE_HUNDRED: Positive = Positive(dec!(900));
+pub const NINE_HUNDRED: Positive = Positive::new_const(dec!(900));
 
 // =============================================================================
 // Multiples of 1000 (1000-10000)
 // =============================================================================
 
 /// A value of one thousand represented as a `Positive` value.
-pub const THOUSAND: Positive = Positive(Decimal::ONE_THOUSAND);
+pub const THOUSAND: Positive = Positive::new_const(Decimal::ONE_THOUSAND);
 
 /// A value of two thousand represented as a `Positive` value.
-pub const TWO_THOUSAND: Positive = Positive(dec!(2000));
+pub const TWO_THOUSAND: Positive = Positive::new_const(dec!(2000));
 
 /// A value of three thousand represented as a `Positive` value.
-pub const THREE_THOUSAND: Positive = Positive(dec!(3000));
+pub const THREE_THOUSAND: Positive = Positive::new_const(dec!(3000));
 
 /// A value of four thousand represented as a `Positive` value.
-pub const FOUR_THOUSAND: Positive = Positive(dec!(4000));
+pub const FOUR_THOUSAND: Positive = Positive::new_const(dec!(4000));
 
 /// A value of five thousand represented as a `Positive` value.
-pub const FIVE_THOUSAND: Positive = Positive(dec!(5000));
+pub const FIVE_THOUSAND: Positive = Positive::new_const(dec!(5000));
 
 /// A value of six thousand represented as a `Positive` value.
-pub const SIX_THOUSAND: Positive = Positive(dec!(6000));
+pub const SIX_THOUSAND: Positive = Positive::new_const(dec!(6000));
 
 /// A value of seven thousand represented as a `Positive` value.
-pub const SEVEN_THOUSAND: Positive = Positive(dec!(7000));
+pub const SEVEN_THOUSAND: Positive = Positive::new_const(dec!(7000));
 
 /// A value of eight thousand represented as a `Positive` value.
-pub const EIGHT_THOUSAND: Positive = Positive(dec!(8000));
+pub const EIGHT_THOUSAND: Positive = Positive::new_const(dec!(8000));
 
 /// A value of nine thousand represented as a `Positive` value.
-pub const NINE_THOUSAND: Positive = Positive(dec!(9000));
+pub const NINE_THOUSAND: Positive = Positive::new_const(dec!(9000));
 
 /// A value of ten thousand represented as a `Positive` value.
-pub const TEN_THOUSAND: Positive = Positive(dec!(10000));
+pub const TEN_THOUSAND: Positive = Positive::new_const(dec!(10000));
 
 // =============================================================================
 // Mathematical Constants
@@ -179,11 +179,11 @@ pub const TEN_THOUSAND: Positive = Positive(dec!(10000));
 
 /// The mathematical constant π (pi) represented as a `Positive` value.
 /// Approximately 3.14159265358979323846.
-pub const PI: Positive = Positive(Decimal::PI);
+pub const PI: Positive = Positive::new_const(Decimal::PI);
 
 /// The mathematical constant e (Euler's number) represented as a `Positive` value.
 /// Approximately 2.71828182845904523536.
-pub const E: Positive = Positive(Decimal::E);
+pub const E: Positive = Positive::new_const(Decimal::E);
 
 // =============================================================================
 // Special Values
@@ -193,8 +193,44 @@ pub const E: Positive = Positive(Decimal::E);
 /// Used for floating-point tolerance in equality checks.
 pub const EPSILON: Decimal = dec!(1e-16);
 
-/// Represents the maximum positive value possible (effectively infinity).
-pub const INFINITY: Positive = Positive(Decimal::MAX);
+/// The smallest value a `Positive` can hold.
+///
+/// This is zero by default, and the smallest representable positive `Decimal`
+/// (`1e-28`) when the `non-zero` feature is enabled. It is the lower
+/// saturation point of the `saturating_*` methods.
+#[cfg(not(feature = "non-zero"))]
+pub const MIN: Positive = Positive::new_const(Decimal::ZERO);
+
+/// The smallest value a `Positive` can hold.
+///
+/// This is zero by default, and the smallest representable positive `Decimal`
+/// (`1e-28`) when the `non-zero` feature is enabled. It is the lower
+/// saturation point of the `saturating_*` methods.
+#[cfg(feature = "non-zero")]
+pub const MIN: Positive = Positive::new_const(Decimal::from_parts(1, 0, 0, false, 28));
+
+/// The largest value a `Positive` can hold (`Decimal::MAX`).
+///
+/// It is the upper saturation point of the `saturating_*` methods.
+pub const MAX: Positive = Positive::new_const(Decimal::MAX);
+
+/// The largest finite value, formerly used as an infinity sentinel.
+///
+/// `Positive` is always finite, so this is the same value as [`MAX`] and
+/// behaves like any other finite number. Use [`EXT_INFINITY`] for an
+/// unbounded value.
+#[deprecated(
+    since = "0.5.0",
+    note = "`Positive` is always finite; use `EXT_INFINITY` for an unbounded value or `MAX` for the largest finite one"
+)]
+pub const INFINITY: Positive = MAX;
+
+/// Positive infinity.
+///
+/// `Positive` is always finite, so infinity is a [`PositiveExt`] value with
+/// its own arithmetic rules rather than a large finite sentinel. The same
+/// value as [`PositiveExt::INFINITY`].
+pub const EXT_INFINITY: PositiveExt = PositiveExt::Infinite;
 
 /// Number of days in a year.
-pub const DAYS_IN_A_YEAR: Positive = Positive(dec!(365.0));
+pub const DAYS_IN_A_YEAR: Positive = Positive::new_const(dec!(365.0));
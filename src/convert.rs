@@ -0,0 +1,414 @@
+/******************************************************************************
+   Author: Joaquín Béjar García
+   Email: jb@taunais.com
+   Date: 18/10/26
+******************************************************************************/
+
+//! Conversions between `Positive` and the primitive integer types.
+//!
+//! Integers go straight through `Decimal`, never through `f64`, so every
+//! value up to `Decimal::MAX` (about 7.9e28) converts exactly.
+//!
+//! * Unsigned integers up to 64 bits and `NonZero` unsigned integers up to
+//!   64 bits convert with `From`. With the `non-zero` feature, zero is not a
+//!   valid `Positive`, so the plain unsigned types use `TryFrom` instead.
+//! * Signed integers, signed `NonZero` integers and the 128-bit types use
+//!   `TryFrom`, failing for negative values (`OutOfBounds`) or values above
+//!   `Decimal::MAX` (`ConversionError` with kind `Overflow`).
+//!
+//! Going the other way, every integer target has the same four forms, e.g.
+//! for `u64`:
+//!
+//! * `to_u64()` truncates the fractional part and panics if out of range.
+//! * `to_u64_checked()` truncates and returns `None` if out of range.
+//! * `to_u64_rounded(strategy)` rounds with a `RoundingStrategy` instead.
+//! * `u32::try_from(positive)` is exact: it fails with `PrecisionLoss` if
+//!   there is a fractional part and with `Overflow` if out of range.
+//!
+//! `u64` and `usize` keep their original infallible `From<Positive>`, which
+//! truncates and returns `0` when out of range, so they have no `TryFrom`;
+//! use their `_checked` and `_rounded` forms instead.
+//!
+//! [`Positive::to_decimal_string`] gives the exact `Decimal` text, keeping
+//! the stored scale.
+
+use crate::Positive;
+use crate::error::{PositiveError, PositiveErrorKind, PositiveResult};
+use rust_decimal::prelude::{FromPrimitive, ToPrimitive};
+use rust_decimal::{Decimal, RoundingStrategy};
+use std::num::{
+    NonZeroI8, NonZeroI16, NonZeroI32, NonZeroI64, NonZeroI128, NonZeroIsize, NonZeroU8,
+    NonZeroU16, NonZeroU32, NonZeroU64, NonZeroU128, NonZeroUsize,
+};
+
+/// Conversions from unsigned integers, infallible unless zero is rejected.
+macro_rules! impl_from_unsigned {
+    ($($int:ty),*) => {
+        $(
+            #[cfg(not(feature = "non-zero"))]
+            impl From<$int> for Positive {
+                fn from(value: $int) -> Self {
+                    Positive::new_const(Decimal::from(value))
+                }
+            }
+
+            #[cfg(feature = "non-zero")]
+            impl TryFrom<$int> for Positive {
+                type Error = PositiveError;
+
+                /// Converts the integer exactly.
+                ///
+                /// # Errors
+                ///
+                /// Returns `PositiveError::OutOfBounds` if the value is zero.
+                fn try_from(value: $int) -> Result<Self, Self::Error> {
+                    Positive::new_decimal(Decimal::from(value))
+                }
+            }
+        )*
+    };
+}
+
+/// Conversions from `NonZero` unsigned integers, which are always valid.
+macro_rules! impl_from_non_zero {
+    ($($int:ty),*) => {
+        $(
+            impl From<$int> for Positive {
+                fn from(value: $int) -> Self {
+                    Positive::new_const(Decimal::from(value.get()))
+                }
+            }
+        )*
+    };
+}
+
+/// Conversions from signed integers, which fit in a `Decimal` but may be
+/// negative. `$prim` is the primitive the type converts into.
+macro_rules! impl_try_from_signed {
+    ($($int:ty => $prim:ty),*) => {
+        $(
+            impl TryFrom<$int> for Positive {
+                type Error = PositiveError;
+
+                /// Converts the integer exactly.
+                ///
+                /// # Errors
+                ///
+                /// Returns `PositiveError::OutOfBounds` if the value is
+                /// negative (or zero with the `non-zero` feature).
+                fn try_from(value: $int) -> Result<Self, Self::Error> {
+                    Positive::new_decimal(Decimal::from(<$prim>::from(value)))
+                }
+            }
+        )*
+    };
+}
+
+/// Conversions from 128-bit integers, which may exceed `Decimal::MAX`.
+/// `$convert` is the `FromPrimitive` method for the underlying primitive.
+macro_rules! impl_try_from_wide {
+    ($($int:ty => $prim:ty, $convert:path),*) => {
+        $(
+            impl TryFrom<$int> for Positive {
+                type Error = PositiveError;
+
+                /// Converts the integer exactly.
+                ///
+                /// # Errors
+                ///
+                /// Returns `PositiveError::ConversionError` with kind `Overflow`
+                /// if the magnitude exceeds `Decimal::MAX`, and
+                /// `PositiveError::OutOfBounds` if the value is negative (or
+                /// zero with the `non-zero` feature).
+                fn try_from(value: $int) -> Result<Self, Self::Error> {
+                    let decimal = $convert(<$prim>::from(value)).ok_or_else(|| {
+                        PositiveError::conversion(
+                            stringify!($int),
+                            "Positive",
+                            PositiveErrorKind::Overflow,
+                            &format!("{value} is out of range for Decimal"),
+                        )
+                    })?;
+                    Positive::new_decimal(decimal)
+                }
+            }
+        )*
+    };
+}
+
+impl_from_unsigned!(u8, u16, u32, u64, usize);
+
+impl_from_non_zero!(NonZeroU8, NonZeroU16, NonZeroU32, NonZeroU64, NonZeroUsize);
+
+impl_try_from_signed!(
+    i8 => i8,
+    i16 => i16,
+    i32 => i32,
+    i64 => i64,
+    isize => isize,
+    NonZeroI8 => i8,
+    NonZeroI16 => i16,
+    NonZeroI32 => i32,
+    NonZeroI64 => i64,
+    NonZeroIsize => isize
+);
+
+impl_try_from_wide!(
+    u128 => u128, Decimal::from_u128,
+    i128 => i128, Decimal::from_i128,
+    NonZeroU128 => u128, Decimal::from_u128,
+    NonZeroI128 => i128, Decimal::from_i128
+);
+
+/// The error for a `Positive` that does not fit in `to_type`.
+fn out_of_range(value: Positive, to_type: &str) -> PositiveError {
+    PositiveError::conversion(
+        "Positive",
+        to_type,
+        PositiveErrorKind::Overflow,
+        &format!("{value} is out of range for {to_type}"),
+    )
+}
+
+/// Converts a whole `Positive` with `convert`, rejecting fractional values.
+fn exact_integer<T>(
+    value: Positive,
+    to_type: &str,
+    convert: impl FnOnce(&Decimal) -> Option<T>,
+) -> PositiveResult<T> {
+    if !value.as_decimal().fract().is_zero() {
+        return Err(PositiveError::conversion(
+            "Positive",
+            to_type,
+            PositiveErrorKind::PrecisionLoss,
+            &format!("{value} has a fractional part"),
+        ));
+    }
+    convert(value.as_decimal()).ok_or_else(|| out_of_range(value, to_type))
+}
+
+/// Conversion methods from `Positive` into primitive integers. `$prim` is
+/// the `ToPrimitive` method for the target.
+macro_rules! impl_to_integer {
+    ($($int:ty => $to:ident, $checked:ident, $rounded:ident, $prim:ident);* $(;)?) => {
+        impl Positive {
+            $(
+                #[doc = concat!("Converts the value to `", stringify!($int), "`, truncating any fractional part.")]
+                ///
+                /// # Panics
+                ///
+                #[doc = concat!("Panics if the value is out of range. Use `", stringify!($checked), "()`")]
+                /// for a non-panicking alternative.
+                #[must_use]
+                pub fn $to(&self) -> $int {
+                    self.$checked().expect(concat!(
+                        "Decimal to ",
+                        stringify!($int),
+                        " conversion failed - value out of range"
+                    ))
+                }
+
+                #[doc = concat!("Converts the value to `", stringify!($int), "`, truncating any fractional part.")]
+                ///
+                /// Returns `None` if the value is out of range.
+                #[must_use]
+                pub fn $checked(&self) -> Option<$int> {
+                    self.as_decimal().$prim()
+                }
+
+                #[doc = concat!("Rounds the value to an integer with `strategy` and converts it to `", stringify!($int), "`.")]
+                ///
+                /// Returns `None` if the rounded value is out of range.
+                #[must_use]
+                pub fn $rounded(&self, strategy: RoundingStrategy) -> Option<$int> {
+                    self.as_decimal().round_dp_with_strategy(0, strategy).$prim()
+                }
+            )*
+        }
+    };
+}
+
+/// Exact `TryFrom<Positive>` conversions into primitive integers. `$prim` is
+/// the `ToPrimitive` method for the target.
+macro_rules! impl_try_into_integer {
+    ($($int:ty => $prim:ident),* $(,)?) => {
+        $(
+            impl TryFrom<Positive> for $int {
+                type Error = PositiveError;
+
+                /// Converts a whole value exactly.
+                ///
+                /// # Errors
+                ///
+                /// Returns `PositiveError::ConversionError` with kind
+                /// `PrecisionLoss` if the value has a fractional part, or
+                /// `Overflow` if it is out of range.
+                fn try_from(value: Positive) -> Result<Self, Self::Error> {
+                    exact_integer(value, stringify!($int), Decimal::$prim)
+                }
+            }
+        )*
+    };
+}
+
+/// Conversions from `Positive` into `NonZero` integers. `$prim` is the
+/// `ToPrimitive` method for the underlying primitive.
+macro_rules! impl_to_non_zero {
+    ($($non_zero:ty => $to:ident, $checked:ident, $rounded:ident, $prim:ident);* $(;)?) => {
+        impl Positive {
+            $(
+                #[doc = concat!("Converts the value to `", stringify!($non_zero), "`, truncating any fractional part.")]
+                ///
+                /// # Panics
+                ///
+                /// Panics if the truncated value is zero or out of range. Use
+                #[doc = concat!("`", stringify!($checked), "()` for a non-panicking alternative.")]
+                #[must_use]
+                pub fn $to(&self) -> $non_zero {
+                    self.$checked().expect(concat!(
+                        "Decimal to ",
+                        stringify!($non_zero),
+                        " conversion failed - value is zero or out of range"
+                    ))
+                }
+
+                #[doc = concat!("Converts the value to `", stringify!($non_zero), "`, truncating any fractional part.")]
+                ///
+                /// Returns `None` if the truncated value is zero or out of range.
+                #[must_use]
+                pub fn $checked(&self) -> Option<$non_zero> {
+                    self.as_decimal().$prim().and_then(<$non_zero>::new)
+                }
+
+                #[doc = concat!("Rounds the value to an integer with `strategy` and converts it to `", stringify!($non_zero), "`.")]
+                ///
+                /// Returns `None` if the rounded value is zero or out of range.
+                #[must_use]
+                pub fn $rounded(&self, strategy: RoundingStrategy) -> Option<$non_zero> {
+                    self.as_decimal()
+                        .round_dp_with_strategy(0, strategy)
+                        .$prim()
+                        .and_then(<$non_zero>::new)
+                }
+            )*
+        }
+
+        $(
+            impl TryFrom<Positive> for $non_zero {
+                type Error = PositiveError;
+
+                /// Converts a whole, non-zero value exactly.
+                ///
+                /// # Errors
+                ///
+                /// Returns `PositiveError::ConversionError` with kind
+                /// `PrecisionLoss` if the value has a fractional part, `Zero`
+                /// if it is zero, or `Overflow` if it is out of range.
+                fn try_from(value: Positive) -> Result<Self, Self::Error> {
+                    let integer = exact_integer(value, stringify!($non_zero), Decimal::$prim)?;
+                    <$non_zero>::new(integer).ok_or_else(|| {
+                        PositiveError::conversion(
+                            "Positive",
+                            stringify!($non_zero),
+                            PositiveErrorKind::Zero,
+                            "value is zero",
+                        )
+                    })
+                }
+            }
+        )*
+    };
+}
+
+impl_to_integer!(
+    u8 => to_u8, to_u8_checked, to_u8_rounded, to_u8;
+    u16 => to_u16, to_u16_checked, to_u16_rounded, to_u16;
+    u32 => to_u32, to_u32_checked, to_u32_rounded, to_u32;
+    u64 => to_u64, to_u64_checked, to_u64_rounded, to_u64;
+    u128 => to_u128, to_u128_checked, to_u128_rounded, to_u128;
+    usize => to_usize, to_usize_checked, to_usize_rounded, to_usize;
+    i64 => to_i64, to_i64_checked, to_i64_rounded, to_i64;
+    i128 => to_i128, to_i128_checked, to_i128_rounded, to_i128;
+);
+
+impl_try_into_integer!(
+    u8 => to_u8,
+    u16 => to_u16,
+    u32 => to_u32,
+    u128 => to_u128,
+    i64 => to_i64,
+    i128 => to_i128,
+);
+
+impl From<Positive> for u64 {
+    /// Truncates the fractional part, returning `0` if out of range.
+    fn from(pos_u64: Positive) -> Self {
+        pos_u64.as_decimal().to_u64().unwrap_or(0)
+    }
+}
+
+impl From<Positive> for usize {
+    /// Truncates the fractional part, returning `0` if out of range.
+    fn from(value: Positive) -> Self {
+        value.as_decimal().to_usize().unwrap_or(0)
+    }
+}
+
+impl_to_non_zero!(
+    NonZeroU64 => to_non_zero_u64, to_non_zero_u64_checked, to_non_zero_u64_rounded, to_u64;
+    NonZeroUsize => to_non_zero_usize, to_non_zero_usize_checked, to_non_zero_usize_rounded, to_usize;
+);
+
+impl Positive {
+    /// Converts the value to a 32-bit floating-point number.
+    ///
+    /// # Panics
+    ///
+    /// This method will panic if the conversion fails. Use `to_f32_checked()`
+    /// for a non-panicking alternative.
+    #[must_use]
+    pub fn to_f32(&self) -> f32 {
+        self.to_f32_checked()
+            .expect("Decimal to f32 conversion failed - value out of range")
+    }
+
+    /// Converts the value to f32, returning None if conversion fails.
+    ///
+    /// Every `Positive` is within the range of `f32`, but digits beyond its
+    /// roughly seven significant digits of precision are rounded.
+    #[must_use]
+    pub fn to_f32_checked(&self) -> Option<f32> {
+        self.as_decimal().to_f32()
+    }
+
+    /// Returns the exact `Decimal` text of the value, keeping the stored
+    /// scale, so trailing zeros are not removed.
+    ///
+    /// ```rust
+    /// use positive::Positive;
+    /// use rust_decimal_macros::dec;
+    ///
+    /// let value = Positive::new_decimal(dec!(1.50)).unwrap();
+    /// assert_eq!(value.to_decimal_string(), "1.50");
+    /// ```
+    #[must_use]
+    pub fn to_decimal_string(&self) -> String {
+        self.as_decimal().to_string()
+    }
+}
+
+impl TryFrom<Positive> for f32 {
+    type Error = PositiveError;
+
+    /// Converts the value to the nearest `f32`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `PositiveError::ConversionError` with kind `Overflow` if the
+    /// value has no `f32` representation.
+    fn try_from(value: Positive) -> Result<Self, Self::Error> {
+        value
+            .to_f32_checked()
+            .ok_or_else(|| out_of_range(value, "f32"))
+    }
+}
@@ -9,8 +9,108 @@
 //! This module provides error handling for operations involving positive decimal values,
 //! including validation, arithmetic operations, conversions, and precision issues.
 
+use rust_decimal::Decimal;
+use rust_decimal::prelude::FromPrimitive;
+use std::fmt;
 use thiserror::Error;
 
+/// Why a value or operation was rejected.
+///
+/// Carried by the value-bearing `PositiveError` variants and returned by
+/// [`PositiveError::kind`], so callers can match on the cause without
+/// inspecting messages.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum PositiveErrorKind {
+    /// The value is below zero.
+    Negative,
+    /// The value is zero where a strictly positive value is required.
+    Zero,
+    /// The input is NaN, or the result is undefined (e.g. `inf - inf`).
+    NaN,
+    /// The input or result is infinite.
+    Infinite,
+    /// The result does not fit in a `Decimal`.
+    Overflow,
+    /// A division or remainder by zero.
+    DivisionByZero,
+    /// The value cannot be represented without losing precision.
+    PrecisionLoss,
+    /// A string could not be parsed.
+    Parse,
+    /// Any other cause, including errors built from a free-form message.
+    Other,
+}
+
+impl PositiveErrorKind {
+    /// Returns a stable, machine-readable identifier for the kind.
+    #[must_use]
+    pub const fn code(self) -> &'static str {
+        match self {
+            PositiveErrorKind::Negative => "negative",
+            PositiveErrorKind::Zero => "zero",
+            PositiveErrorKind::NaN => "nan",
+            PositiveErrorKind::Infinite => "infinite",
+            PositiveErrorKind::Overflow => "overflow",
+            PositiveErrorKind::DivisionByZero => "division_by_zero",
+            PositiveErrorKind::PrecisionLoss => "precision_loss",
+            PositiveErrorKind::Parse => "parse",
+            PositiveErrorKind::Other => "other",
+        }
+    }
+
+    /// Classifies a `Decimal` that is not a valid `Positive`.
+    pub(crate) fn of_decimal(value: Decimal) -> Self {
+        if value.is_sign_negative() && !value.is_zero() {
+            PositiveErrorKind::Negative
+        } else {
+            PositiveErrorKind::Zero
+        }
+    }
+
+    /// Classifies an `f64` that could not become a `Positive`.
+    pub(crate) fn of_f64(value: f64) -> Self {
+        if value.is_nan() {
+            PositiveErrorKind::NaN
+        } else if value.is_infinite() {
+            PositiveErrorKind::Infinite
+        } else if value < 0.0 {
+            PositiveErrorKind::Negative
+        } else if value == 0.0 {
+            PositiveErrorKind::Zero
+        } else {
+            PositiveErrorKind::Other
+        }
+    }
+}
+
+impl fmt::Display for PositiveErrorKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.code())
+    }
+}
+
+/// The lower bound of `Positive` as reported in `OutOfBounds` errors.
+pub(crate) fn min_bound() -> f64 {
+    if cfg!(feature = "non-zero") {
+        f64::MIN_POSITIVE
+    } else {
+        0.0
+    }
+}
+
+/// Formats an optional offending value as ` {value}`, or nothing.
+struct OptionalValue<'a>(&'a Option<Decimal>);
+
+impl fmt::Display for OptionalValue<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.0 {
+            Some(value) => write!(f, " {value}"),
+            None => Ok(()),
+        }
+    }
+}
+
 /// Represents errors that can occur during positive decimal operations.
 ///
 /// This enum provides a structured way to handle various error conditions that may arise
@@ -24,17 +124,24 @@ use thiserror::Error;
 /// * `ConversionError` - Error when converting between types
 /// * `OutOfBounds` - Value exceeds defined limits
 /// * `InvalidPrecision` - Invalid decimal precision settings
+/// * `ParseError` - String input that cannot be parsed
 /// * `Other` - Catch-all for other errors
-#[derive(Error, Debug)]
+///
+/// Use [`kind`](Self::kind) to match on the cause and [`code`](Self::code)
+/// for a stable identifier suitable for logs and APIs.
+#[derive(Error, Debug, Clone, PartialEq)]
+#[non_exhaustive]
 pub enum PositiveError {
     /// Error when attempting to create a positive decimal from an invalid value.
     ///
     /// Occurs when a value cannot be properly represented as a positive decimal,
     /// such as when it's NaN, infinity, negative, or otherwise unsuitable.
-    #[error("Invalid positive value {value}: {reason}")]
+    #[error("Invalid positive value{}: {reason}", OptionalValue(value))]
     InvalidValue {
-        /// The problematic value that caused the error.
-        value: f64,
+        /// The offending value, if it can be represented as a `Decimal`.
+        value: Option<Decimal>,
+        /// Why the value was rejected.
+        kind: PositiveErrorKind,
         /// Detailed explanation of why the value is invalid.
         reason: String,
     },
@@ -48,6 +155,10 @@ pub enum PositiveError {
     ArithmeticError {
         /// The operation that failed (e.g., "subtraction", "division").
         operation: String,
+        /// The invalid result, if the operation produced one.
+        value: Option<Decimal>,
+        /// Why the operation failed.
+        kind: PositiveErrorKind,
         /// Detailed explanation of why the operation failed.
         reason: String,
     },
@@ -63,6 +174,8 @@ pub enum PositiveError {
         from_type: String,
         /// The destination type being converted to.
         to_type: String,
+        /// Why the conversion failed.
+        kind: PositiveErrorKind,
         /// Detailed explanation of why the conversion failed.
         reason: String,
     },
@@ -70,11 +183,18 @@ pub enum PositiveError {
     /// Error when a decimal value exceeds its bounds.
     ///
     /// Occurs when a decimal value falls outside of acceptable minimum
-    /// or maximum values for a specific calculation context.
-    #[error("Value {value} is out of bounds (min: {min}, max: {max})")]
+    /// or maximum values for a specific calculation context, including a
+    /// negative (or, with `non-zero`, zero) value passed to a constructor.
+    #[error(
+        "Value{} is out of bounds (min: {min}, max: {max})",
+        OptionalValue(value)
+    )]
     OutOfBounds {
-        /// The value that is out of bounds.
-        value: f64,
+        /// The value that is out of bounds, if it can be represented as a
+        /// `Decimal`.
+        value: Option<Decimal>,
+        /// Why the value was rejected.
+        kind: PositiveErrorKind,
         /// The minimum acceptable value.
         min: f64,
         /// The maximum acceptable value.
@@ -93,6 +213,21 @@ pub enum PositiveError {
         reason: String,
     },
 
+    /// Error when a string cannot be parsed as a positive decimal.
+    ///
+    /// Occurs when `FromStr` or `Positive::from_str_with` meets a character or
+    /// construct it does not accept. `position` is the byte offset in `input`
+    /// where parsing stopped.
+    #[error("Failed to parse '{input}' at position {position}: {reason}")]
+    ParseError {
+        /// The string that failed to parse.
+        input: String,
+        /// Byte offset of the offending character.
+        position: usize,
+        /// Detailed explanation of why parsing failed.
+        reason: String,
+    },
+
     /// Catch-all error for other positive decimal errors.
     #[error("Positive error: {0}")]
     Other(String),
@@ -122,11 +257,35 @@ impl PositiveError {
     #[must_use]
     pub fn invalid_value(value: f64, reason: &str) -> Self {
         PositiveError::InvalidValue {
-            value,
+            value: Decimal::from_f64(value),
+            kind: PositiveErrorKind::of_f64(value),
             reason: reason.to_string(),
         }
     }
 
+    /// Creates an `OutOfBounds` error for a `Decimal` that is not positive.
+    ///
+    /// The kind is `Negative` for values below zero and `Zero` otherwise.
+    /// The bounds are those of `Positive`: from zero (or the smallest
+    /// positive `f64` with the `non-zero` feature) to infinity.
+    ///
+    /// # Arguments
+    ///
+    /// * `value` - The rejected value
+    ///
+    /// # Returns
+    ///
+    /// A new `PositiveError::OutOfBounds` instance
+    #[must_use]
+    pub fn not_positive(value: Decimal) -> Self {
+        PositiveError::OutOfBounds {
+            value: Some(value),
+            kind: PositiveErrorKind::of_decimal(value),
+            min: min_bound(),
+            max: f64::INFINITY,
+        }
+    }
+
     /// Creates a new `ArithmeticError` error.
     ///
     /// # Arguments
@@ -139,8 +298,32 @@ impl PositiveError {
     /// A new `PositiveError::ArithmeticError` instance
     #[must_use]
     pub fn arithmetic_error(operation: &str, reason: &str) -> Self {
+        PositiveError::arithmetic(operation, PositiveErrorKind::Other, None, reason)
+    }
+
+    /// Creates a new `ArithmeticError` error with a structured cause.
+    ///
+    /// # Arguments
+    ///
+    /// * `operation` - The name of the operation that failed
+    /// * `kind` - Why the operation failed
+    /// * `value` - The invalid result, if one was produced
+    /// * `reason` - Explanation of why the operation failed
+    ///
+    /// # Returns
+    ///
+    /// A new `PositiveError::ArithmeticError` instance
+    #[must_use]
+    pub fn arithmetic(
+        operation: &str,
+        kind: PositiveErrorKind,
+        value: Option<Decimal>,
+        reason: &str,
+    ) -> Self {
         PositiveError::ArithmeticError {
             operation: operation.to_string(),
+            value,
+            kind,
             reason: reason.to_string(),
         }
     }
@@ -158,9 +341,32 @@ impl PositiveError {
     /// A new `PositiveError::ConversionError` instance
     #[must_use]
     pub fn conversion_error(from_type: &str, to_type: &str, reason: &str) -> Self {
+        PositiveError::conversion(from_type, to_type, PositiveErrorKind::Other, reason)
+    }
+
+    /// Creates a new `ConversionError` error with a structured cause.
+    ///
+    /// # Arguments
+    ///
+    /// * `from_type` - The source type being converted from
+    /// * `to_type` - The destination type being converted to
+    /// * `kind` - Why the conversion failed
+    /// * `reason` - Explanation of why the conversion failed
+    ///
+    /// # Returns
+    ///
+    /// A new `PositiveError::ConversionError` instance
+    #[must_use]
+    pub fn conversion(
+        from_type: &str,
+        to_type: &str,
+        kind: PositiveErrorKind,
+        reason: &str,
+    ) -> Self {
         PositiveError::ConversionError {
             from_type: from_type.to_string(),
             to_type: to_type.to_string(),
+            kind,
             reason: reason.to_string(),
         }
     }
@@ -173,12 +379,24 @@ impl PositiveError {
     /// * `min` - The lower bound (inclusive) of the valid range
     /// * `max` - The upper bound (inclusive) of the valid range
     ///
+    /// The kind is `Negative` or `NaN` for such values and `Other` otherwise.
+    ///
     /// # Returns
     ///
     /// A new `PositiveError::OutOfBounds` instance
     #[must_use]
     pub fn out_of_bounds(value: f64, min: f64, max: f64) -> Self {
-        PositiveError::OutOfBounds { value, min, max }
+        let kind = if value.is_nan() || value < 0.0 {
+            PositiveErrorKind::of_f64(value)
+        } else {
+            PositiveErrorKind::Other
+        };
+        PositiveError::OutOfBounds {
+            value: Decimal::from_f64(value),
+            kind,
+            min,
+            max,
+        }
     }
 
     /// Creates a new `InvalidPrecision` error.
@@ -198,6 +416,85 @@ impl PositiveError {
             reason: reason.to_string(),
         }
     }
+
+    /// Creates a new `ParseError` error.
+    ///
+    /// # Arguments
+    ///
+    /// * `input` - The string that failed to parse
+    /// * `position` - Byte offset in `input` where parsing stopped
+    /// * `reason` - Explanation of why parsing failed
+    ///
+    /// # Returns
+    ///
+    /// A new `PositiveError::ParseError` instance
+    #[must_use]
+    pub fn parse_error(input: &str, position: usize, reason: &str) -> Self {
+        PositiveError::ParseError {
+            input: input.to_string(),
+            position,
+            reason: reason.to_string(),
+        }
+    }
+
+    /// Returns why the error occurred.
+    ///
+    /// Variants without a stored kind are classified from their variant:
+    /// `ParseError` is `Parse` and everything else is `Other`.
+    #[must_use]
+    pub fn kind(&self) -> PositiveErrorKind {
+        match self.stored_kind() {
+            Some(kind) => kind,
+            None if matches!(self, PositiveError::ParseError { .. }) => PositiveErrorKind::Parse,
+            None => PositiveErrorKind::Other,
+        }
+    }
+
+    /// The kind carried by the value-bearing variants.
+    fn stored_kind(&self) -> Option<PositiveErrorKind> {
+        match self {
+            PositiveError::InvalidValue { kind, .. }
+            | PositiveError::ArithmeticError { kind, .. }
+            | PositiveError::ConversionError { kind, .. }
+            | PositiveError::OutOfBounds { kind, .. } => Some(*kind),
+            _ => None,
+        }
+    }
+
+    /// Returns the offending `Decimal`, when the error carries one.
+    #[must_use]
+    pub fn value(&self) -> Option<Decimal> {
+        match self {
+            PositiveError::InvalidValue { value, .. }
+            | PositiveError::ArithmeticError { value, .. }
+            | PositiveError::OutOfBounds { value, .. } => *value,
+            _ => None,
+        }
+    }
+
+    /// Returns a stable, machine-readable error code.
+    ///
+    /// The code is `<variant>.<kind>` for the variants that carry a kind,
+    /// with `<kind>` from [`PositiveErrorKind::code`]
+    /// (e.g. `"arithmetic.division_by_zero"`), and `<variant>` otherwise
+    /// (e.g. `"parse"`). Codes never change once published, so they are safe
+    /// to persist or expose in APIs.
+    #[must_use]
+    pub fn code(&self) -> String {
+        let variant = match self {
+            PositiveError::InvalidValue { .. } => "invalid_value",
+            PositiveError::ArithmeticError { .. } => "arithmetic",
+            PositiveError::ConversionError { .. } => "conversion",
+            PositiveError::OutOfBounds { .. } => "out_of_bounds",
+            PositiveError::InvalidPrecision { .. } => "invalid_precision",
+            PositiveError::ParseError { .. } => "parse",
+            PositiveError::Other(_) => "other",
+        };
+        match self.stored_kind() {
+            Some(kind) => format!("{variant}.{}", kind.code()),
+            None => variant.to_string(),
+        }
+    }
 }
 
 impl From<&str> for PositiveError {
@@ -251,6 +548,99 @@ mod tests {
         assert!(error.to_string().contains("non-negative"));
     }
 
+    #[test]
+    fn test_parse_error() {
+        let error = PositiveError::parse_error("12x", 2, "unexpected character 'x'");
+        assert!(matches!(
+            error,
+            PositiveError::ParseError { position: 2, .. }
+        ));
+        assert!(error.to_string().contains("at position 2"));
+    }
+
+    #[test]
+    fn test_invalid_value_keeps_decimal_and_kind() {
+        let error = PositiveError::invalid_value(-1.5, "Value cannot be negative");
+        assert_eq!(error.kind(), PositiveErrorKind::Negative);
+        assert_eq!(error.value(), Decimal::from_f64(-1.5));
+        assert_eq!(error.code(), "invalid_value.negative");
+
+        let error = PositiveError::invalid_value(f64::NAN, "not a number");
+        assert_eq!(error.kind(), PositiveErrorKind::NaN);
+        assert_eq!(error.value(), None);
+        assert_eq!(error.to_string(), "Invalid positive value: not a number");
+    }
+
+    #[test]
+    fn test_not_positive() {
+        let error = PositiveError::not_positive(Decimal::new(-25, 1));
+        assert_eq!(error.kind(), PositiveErrorKind::Negative);
+        assert_eq!(error.value(), Some(Decimal::new(-25, 1)));
+        assert_eq!(error.code(), "out_of_bounds.negative");
+        assert!(
+            error
+                .to_string()
+                .starts_with("Value -2.5 is out of bounds (min: ")
+        );
+
+        let error = PositiveError::not_positive(Decimal::ZERO);
+        assert_eq!(error.kind(), PositiveErrorKind::Zero);
+        assert_eq!(error.code(), "out_of_bounds.zero");
+    }
+
+    #[test]
+    fn test_arithmetic_builders() {
+        let error = PositiveError::arithmetic_error("subtraction", "Result would be negative");
+        assert_eq!(error.kind(), PositiveErrorKind::Other);
+        assert_eq!(error.code(), "arithmetic.other");
+
+        let error = PositiveError::arithmetic(
+            "division",
+            PositiveErrorKind::DivisionByZero,
+            None,
+            "division by zero",
+        );
+        assert_eq!(error.code(), "arithmetic.division_by_zero");
+        assert_eq!(
+            error.to_string(),
+            "Arithmetic error during division: division by zero"
+        );
+    }
+
+    #[test]
+    fn test_codes_of_unstructured_variants() {
+        assert_eq!(
+            PositiveError::parse_error("x", 0, "bad").kind(),
+            PositiveErrorKind::Parse
+        );
+        assert_eq!(PositiveError::parse_error("x", 0, "bad").code(), "parse");
+        assert_eq!(
+            PositiveError::out_of_bounds(-1.0, 0.0, 1.0).kind(),
+            PositiveErrorKind::Negative
+        );
+        assert_eq!(
+            PositiveError::out_of_bounds(-1.0, 0.0, 1.0).value(),
+            Some(Decimal::NEGATIVE_ONE)
+        );
+        assert_eq!(
+            PositiveError::out_of_bounds(2.0, 0.0, 1.0).code(),
+            "out_of_bounds.other"
+        );
+        assert_eq!(
+            PositiveError::out_of_bounds(f64::NAN, 0.0, 1.0).to_string(),
+            "Value is out of bounds (min: 0, max: 1)"
+        );
+        assert_eq!(
+            PositiveError::invalid_precision(-1, "bad").code(),
+            "invalid_precision"
+        );
+        assert_eq!(PositiveError::from("bad").code(), "other");
+        assert_eq!(
+            PositiveErrorKind::PrecisionLoss.to_string(),
+            "precision_loss"
+        );
+    }
+
     #[test]
     fn test_from_str() {
         let error: PositiveError = "Custom error message".into();
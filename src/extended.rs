@@ -0,0 +1,427 @@
+/******************************************************************************
+   Author: Joaquín Béjar García
+   Email: jb@taunais.com
+   Date: 18/10/26
+******************************************************************************/
+
+//! An extended positive number line that includes positive infinity.
+//!
+//! `Positive` is always a finite `Decimal`. Configuration values that need an
+//! explicit "no limit" state (risk caps, open-ended ranges, ...) use
+//! [`PositiveExt`] instead, whose [`Infinite`](PositiveExt::Infinite) variant
+//! follows IEEE-like arithmetic rules rather than acting as a very large
+//! finite number.
+
+use crate::Positive;
+use crate::error::{PositiveError, PositiveErrorKind, PositiveResult};
+use crate::positive::positive_result;
+use rust_decimal::Decimal;
+use serde::de::value::MapAccessDeserializer;
+use serde::de::{IntoDeserializer, MapAccess, Visitor};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use std::cmp::Ordering;
+use std::fmt;
+use std::fmt::Display;
+use std::ops::{Add, Div, Mul, Sub};
+use std::str::FromStr;
+
+/// A `Positive` value or positive infinity.
+///
+/// `Infinite` compares greater than every finite value, and arithmetic
+/// follows IEEE-like rules:
+///
+/// | Operation              | Result                                   |
+/// |------------------------|------------------------------------------|
+/// | `inf + x`, `x + inf`   | `inf`                                    |
+/// | `inf - finite`         | `inf`                                    |
+/// | `finite - inf`         | error (the result would be negative)     |
+/// | `inf - inf`            | error (undefined)                        |
+/// | `inf * x` (`x > 0`)    | `inf`                                    |
+/// | `inf * 0`              | error (undefined)                        |
+/// | `inf / finite`         | `inf`                                    |
+/// | `finite / inf`         | `0` (an error with `non-zero`)           |
+/// | `inf / inf`            | error (undefined)                        |
+/// | `x / 0`                | error (division by zero)                 |
+///
+/// A finite operation that overflows `Decimal` produces `Infinite` instead of
+/// an error. The `checked_*` methods report errors as
+/// `PositiveError::ArithmeticError`; the operators panic with the same
+/// message.
+///
+/// # Example
+///
+/// ```rust
+/// use positive::{PositiveExt, pos_or_panic};
+///
+/// let limit = PositiveExt::INFINITY;
+/// let used = PositiveExt::from(pos_or_panic!(250.0));
+///
+/// assert!(used < limit);
+/// assert!((limit - used).is_infinite());
+/// assert!((used + pos_or_panic!(50.0)).is_finite());
+/// assert!(limit.checked_sub(&limit).is_err());
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum PositiveExt {
+    /// A finite `Positive` value.
+    Finite(Positive),
+    /// Positive infinity.
+    Infinite,
+}
+
+impl PositiveExt {
+    /// Positive infinity.
+    pub const INFINITY: PositiveExt = PositiveExt::Infinite;
+
+    /// Creates a `PositiveExt` from an `f64`.
+    ///
+    /// `f64::INFINITY` maps to `Infinite`; every other value goes through
+    /// [`Positive::new`].
+    ///
+    /// # Errors
+    ///
+    /// Returns the `PositiveError` produced by [`Positive::new`] for NaN,
+    /// negative and out-of-range values.
+    pub fn new(value: f64) -> PositiveResult<Self> {
+        if value == f64::INFINITY {
+            Ok(PositiveExt::Infinite)
+        } else {
+            Positive::new(value).map(PositiveExt::Finite)
+        }
+    }
+
+    /// Returns `true` if the value is positive infinity.
+    #[must_use]
+    pub const fn is_infinite(&self) -> bool {
+        matches!(self, PositiveExt::Infinite)
+    }
+
+    /// Returns `true` if the value is finite.
+    #[must_use]
+    pub const fn is_finite(&self) -> bool {
+        !self.is_infinite()
+    }
+
+    /// Returns the finite value, or `None` for infinity.
+    #[must_use]
+    pub const fn finite(&self) -> Option<Positive> {
+        match self {
+            PositiveExt::Finite(value) => Some(*value),
+            PositiveExt::Infinite => None,
+        }
+    }
+
+    /// Converts the value to `f64`, mapping infinity to `f64::INFINITY`.
+    #[must_use]
+    pub fn to_f64(&self) -> f64 {
+        match self {
+            PositiveExt::Finite(value) => value.to_f64(),
+            PositiveExt::Infinite => f64::INFINITY,
+        }
+    }
+
+    /// Checked addition.
+    ///
+    /// # Errors
+    ///
+    /// Addition of two extended values is always defined; the `Result` keeps
+    /// the signature consistent with the other `checked_*` methods.
+    pub fn checked_add(&self, rhs: &Self) -> PositiveResult<Self> {
+        match (self, rhs) {
+            (PositiveExt::Finite(lhs), PositiveExt::Finite(rhs)) => {
+                finite_or_overflow("addition", lhs.into_inner().checked_add(rhs.into_inner()))
+            }
+            _ => Ok(PositiveExt::Infinite),
+        }
+    }
+
+    /// Checked subtraction.
+    ///
+    /// # Errors
+    ///
+    /// Returns `PositiveError::ArithmeticError` for `inf - inf`, when a finite
+    /// value is reduced by infinity, or when a finite result is invalid.
+    pub fn checked_sub(&self, rhs: &Self) -> PositiveResult<Self> {
+        match (self, rhs) {
+            (PositiveExt::Finite(lhs), PositiveExt::Finite(rhs)) => {
+                lhs.checked_sub(rhs).map(PositiveExt::Finite)
+            }
+            (PositiveExt::Infinite, PositiveExt::Finite(_)) => Ok(PositiveExt::Infinite),
+            (PositiveExt::Finite(_), PositiveExt::Infinite) => Err(PositiveError::arithmetic(
+                "subtraction",
+                PositiveErrorKind::Negative,
+                None,
+                "result would be negative infinity",
+            )),
+            (PositiveExt::Infinite, PositiveExt::Infinite) => Err(PositiveError::arithmetic(
+                "subtraction",
+                PositiveErrorKind::NaN,
+                None,
+                "infinity minus infinity is undefined",
+            )),
+        }
+    }
+
+    /// Checked multiplication.
+    ///
+    /// # Errors
+    ///
+    /// Returns `PositiveError::ArithmeticError` when infinity is multiplied by
+    /// zero, or when a finite result is invalid.
+    pub fn checked_mul(&self, rhs: &Self) -> PositiveResult<Self> {
+        match (self, rhs) {
+            (PositiveExt::Finite(lhs), PositiveExt::Finite(rhs)) => finite_or_overflow(
+                "multiplication",
+                lhs.into_inner().checked_mul(rhs.into_inner()),
+            ),
+            (PositiveExt::Finite(value), PositiveExt::Infinite)
+            | (PositiveExt::Infinite, PositiveExt::Finite(value))
+                if value.is_zero() =>
+            {
+                Err(PositiveError::arithmetic(
+                    "multiplication",
+                    PositiveErrorKind::NaN,
+                    None,
+                    "infinity times zero is undefined",
+                ))
+            }
+            _ => Ok(PositiveExt::Infinite),
+        }
+    }
+
+    /// Checked division.
+    ///
+    /// # Errors
+    ///
+    /// Returns `PositiveError::ArithmeticError` on division by zero, for
+    /// `inf / inf`, or when a finite result is invalid.
+    pub fn checked_div(&self, rhs: &Self) -> PositiveResult<Self> {
+        match (self, rhs) {
+            (_, PositiveExt::Finite(value)) if value.is_zero() => Err(PositiveError::arithmetic(
+                "division",
+                PositiveErrorKind::DivisionByZero,
+                None,
+                "division by zero",
+            )),
+            (PositiveExt::Finite(lhs), PositiveExt::Finite(rhs)) => {
+                finite_or_overflow("division", lhs.into_inner().checked_div(rhs.into_inner()))
+            }
+            (PositiveExt::Infinite, PositiveExt::Finite(_)) => Ok(PositiveExt::Infinite),
+            (PositiveExt::Finite(_), PositiveExt::Infinite) => {
+                positive_result("division", Some(Decimal::ZERO)).map(PositiveExt::Finite)
+            }
+            (PositiveExt::Infinite, PositiveExt::Infinite) => Err(PositiveError::arithmetic(
+                "division",
+                PositiveErrorKind::NaN,
+                None,
+                "infinity divided by infinity is undefined",
+            )),
+        }
+    }
+}
+
+/// Wraps a finite `Decimal` result, treating `None` (overflow) as infinity.
+fn finite_or_overflow(operation: &str, value: Option<Decimal>) -> PositiveResult<PositiveExt> {
+    match value {
+        Some(value) => positive_result(operation, Some(value)).map(PositiveExt::Finite),
+        None => Ok(PositiveExt::Infinite),
+    }
+}
+
+impl From<Positive> for PositiveExt {
+    fn from(value: Positive) -> Self {
+        PositiveExt::Finite(value)
+    }
+}
+
+impl TryFrom<PositiveExt> for Positive {
+    type Error = PositiveError;
+
+    fn try_from(value: PositiveExt) -> Result<Self, Self::Error> {
+        value.finite().ok_or_else(|| {
+            PositiveError::conversion(
+                "PositiveExt",
+                "Positive",
+                PositiveErrorKind::Infinite,
+                "infinity has no finite representation",
+            )
+        })
+    }
+}
+
+impl PartialEq<Positive> for PositiveExt {
+    fn eq(&self, other: &Positive) -> bool {
+        self.finite() == Some(*other)
+    }
+}
+
+impl PartialEq<PositiveExt> for Positive {
+    fn eq(&self, other: &PositiveExt) -> bool {
+        other == self
+    }
+}
+
+impl PartialOrd<Positive> for PositiveExt {
+    fn partial_cmp(&self, other: &Positive) -> Option<Ordering> {
+        Some(self.cmp(&PositiveExt::Finite(*other)))
+    }
+}
+
+impl PartialOrd<PositiveExt> for Positive {
+    fn partial_cmp(&self, other: &PositiveExt) -> Option<Ordering> {
+        Some(PositiveExt::Finite(*self).cmp(other))
+    }
+}
+
+impl Display for PositiveExt {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            PositiveExt::Finite(value) => Display::fmt(value, f),
+            PositiveExt::Infinite => f.pad("inf"),
+        }
+    }
+}
+
+impl FromStr for PositiveExt {
+    type Err = <Positive as FromStr>::Err;
+
+    /// Parses `inf` or `infinity` (case-insensitive, optionally prefixed with
+    /// `+`) as infinity, and anything else as a `Positive`. Surrounding
+    /// whitespace is ignored in both cases.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let trimmed = s.trim();
+        let unsigned = trimmed.strip_prefix('+').unwrap_or(trimmed);
+        if unsigned.eq_ignore_ascii_case("inf") || unsigned.eq_ignore_ascii_case("infinity") {
+            Ok(PositiveExt::Infinite)
+        } else {
+            Positive::from_str(trimmed).map(PositiveExt::Finite)
+        }
+    }
+}
+
+/// Finite values serialize exactly like `Positive`; infinity serializes as
+/// the string `"inf"`, since formats such as JSON cannot represent an
+/// infinite float.
+impl Serialize for PositiveExt {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        match self {
+            PositiveExt::Finite(value) => value.serialize(serializer),
+            PositiveExt::Infinite => serializer.serialize_str("inf"),
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for PositiveExt {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        struct PositiveExtVisitor;
+
+        impl<'de> Visitor<'de> for PositiveExtVisitor {
+            type Value = PositiveExt;
+
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("a positive number or \"inf\"")
+            }
+
+            fn visit_str<E>(self, value: &str) -> Result<Self::Value, E>
+            where
+                E: serde::de::Error,
+            {
+                PositiveExt::from_str(value).map_err(serde::de::Error::custom)
+            }
+
+            fn visit_i64<E>(self, value: i64) -> Result<Self::Value, E>
+            where
+                E: serde::de::Error,
+            {
+                Positive::deserialize(value.into_deserializer()).map(PositiveExt::Finite)
+            }
+
+            fn visit_u64<E>(self, value: u64) -> Result<Self::Value, E>
+            where
+                E: serde::de::Error,
+            {
+                Positive::deserialize(value.into_deserializer()).map(PositiveExt::Finite)
+            }
+
+            fn visit_f64<E>(self, value: f64) -> Result<Self::Value, E>
+            where
+                E: serde::de::Error,
+            {
+                if value == f64::INFINITY {
+                    Ok(PositiveExt::Infinite)
+                } else {
+                    Positive::deserialize(value.into_deserializer()).map(PositiveExt::Finite)
+                }
+            }
+
+            fn visit_f32<E>(self, value: f32) -> Result<Self::Value, E>
+            where
+                E: serde::de::Error,
+            {
+                if value == f32::INFINITY {
+                    Ok(PositiveExt::Infinite)
+                } else {
+                    Positive::deserialize(value.into_deserializer()).map(PositiveExt::Finite)
+                }
+            }
+
+            fn visit_i128<E>(self, value: i128) -> Result<Self::Value, E>
+            where
+                E: serde::de::Error,
+            {
+                Positive::deserialize(value.into_deserializer()).map(PositiveExt::Finite)
+            }
+
+            fn visit_u128<E>(self, value: u128) -> Result<Self::Value, E>
+            where
+                E: serde::de::Error,
+            {
+                Positive::deserialize(value.into_deserializer()).map(PositiveExt::Finite)
+            }
+
+            fn visit_map<A>(self, map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                Positive::deserialize(MapAccessDeserializer::new(map)).map(PositiveExt::Finite)
+            }
+        }
+
+        deserializer.deserialize_any(PositiveExtVisitor)
+    }
+}
+
+/// Implements a binary operator for `PositiveExt` with `PositiveExt` and
+/// `Positive` right-hand sides, panicking with the `PositiveError` message
+/// where the matching `checked_*` method would fail.
+macro_rules! impl_ext_ops {
+    ($trait:ident, $method:ident, $checked:ident) => {
+        impl $trait<PositiveExt> for PositiveExt {
+            type Output = PositiveExt;
+            #[track_caller]
+            fn $method(self, rhs: PositiveExt) -> PositiveExt {
+                self.$checked(&rhs).unwrap_or_else(|e| panic!("{e}"))
+            }
+        }
+
+        impl $trait<Positive> for PositiveExt {
+            type Output = PositiveExt;
+            #[track_caller]
+            fn $method(self, rhs: Positive) -> PositiveExt {
+                self.$checked(&PositiveExt::Finite(rhs))
+                    .unwrap_or_else(|e| panic!("{e}"))
+            }
+        }
+    };
+}
+
+impl_ext_ops!(Add, add, checked_add);
+impl_ext_ops!(Sub, sub, checked_sub);
+impl_ext_ops!(Mul, mul, checked_mul);
+impl_ext_ops!(Div, div, checked_div);
@@ -0,0 +1,209 @@
+/******************************************************************************
+   Author: Joaquín Béjar García
+   Email: jb@taunais.com
+   Date: 18/10/26
+******************************************************************************/
+
+//! Conversion of `f64` inputs into `Decimal`.
+//!
+//! [`FromF64Mode`] selects which decimal digits of an `f64` are kept, and
+//! [`Positive::from_f64_with`] applies it. Every `f64` constructor funnels
+//! through here so that NaN, infinities, negative values and magnitudes
+//! outside the `Decimal` range are classified the same way everywhere.
+//!
+//! The policy for special inputs is:
+//!
+//! * `-0.0` is zero. The sign is dropped, so it is accepted as `0` (and
+//!   rejected as `Zero` with the `non-zero` feature).
+//! * Non-zero magnitudes below `1e-28`, which includes every subnormal `f64`,
+//!   are rejected with `PrecisionLoss` instead of silently becoming zero.
+
+use crate::Positive;
+use crate::error::{PositiveError, PositiveErrorKind, PositiveResult, min_bound};
+use crate::positive::round_significant;
+use rust_decimal::Decimal;
+use rust_decimal::prelude::ToPrimitive;
+use std::str::FromStr;
+
+/// Classifies inputs that no `Decimal` conversion can accept.
+///
+/// Returns `Ok(Some(Decimal::ZERO))` for `±0.0`, `Ok(None)` for values that
+/// still need converting, and an error otherwise.
+fn classify(value: f64) -> PositiveResult<Option<Decimal>> {
+    if value.is_nan() {
+        return Err(PositiveError::conversion(
+            "f64",
+            "Positive",
+            PositiveErrorKind::NaN,
+            "NaN is not a number",
+        ));
+    }
+    if value.is_infinite() {
+        return Err(PositiveError::conversion(
+            "f64",
+            "Positive",
+            PositiveErrorKind::Infinite,
+            "infinity has no finite representation",
+        ));
+    }
+    if value == 0.0 {
+        return Ok(Some(Decimal::ZERO));
+    }
+    if value < 0.0 {
+        return Err(PositiveError::out_of_bounds(
+            value,
+            min_bound(),
+            f64::INFINITY,
+        ));
+    }
+    if value >= Decimal::MAX.to_f64().unwrap_or(f64::MAX) {
+        return Err(PositiveError::conversion(
+            "f64",
+            "Positive",
+            PositiveErrorKind::Overflow,
+            &format!("{value} is too large for Decimal"),
+        ));
+    }
+    Ok(None)
+}
+
+/// The error for a non-zero value that cannot be held without rounding.
+fn precision_loss(value: f64, reason: &str) -> PositiveError {
+    PositiveError::conversion(
+        "f64",
+        "Positive",
+        PositiveErrorKind::PrecisionLoss,
+        &format!("{value} {reason}"),
+    )
+}
+
+/// How an `f64` is turned into a `Decimal`.
+///
+/// An `f64` holds a binary fraction, so most decimal literals are stored
+/// approximately (`0.1` is really `0.1000000000000000055511...`). The mode
+/// decides which decimal digits of that binary value are kept.
+///
+/// The default, [`ShortestRoundTrip`](Self::ShortestRoundTrip), is used by
+/// [`Positive::new`], `TryFrom<f64>`, the `pos!` family of macros and the
+/// arithmetic operators that take an `f64` operand. Rounding modes round half
+/// to even, like [`Positive::round_to`].
+///
+/// # Example
+///
+/// ```rust
+/// use positive::{FromF64Mode, Positive};
+///
+/// let value = 0.1 + 0.2;
+/// let digits = |mode| Positive::from_f64_with(value, mode).unwrap().to_string();
+///
+/// assert_eq!(digits(FromF64Mode::ShortestRoundTrip), "0.30000000000000004");
+/// assert_eq!(digits(FromF64Mode::FullExpansion), "0.3000000000000000444089209849");
+/// assert_eq!(digits(FromF64Mode::SignificantDigits(3)), "0.3");
+/// assert_eq!(digits(FromF64Mode::DecimalPlaces(2)), "0.3");
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub enum FromF64Mode {
+    /// The shortest decimal that converts back to the identical `f64`, i.e.
+    /// the digits `f64`'s `Display` prints. Digits beyond 28 decimal places
+    /// are rounded.
+    #[default]
+    ShortestRoundTrip,
+    /// The binary value written out in full, truncated to the 28 decimal
+    /// places a `Decimal` can hold.
+    FullExpansion,
+    /// The shortest round-trip digits rounded to this many significant digits,
+    /// without trailing zeros.
+    SignificantDigits(u32),
+    /// The shortest round-trip digits rounded to this many decimal places.
+    DecimalPlaces(u32),
+}
+
+impl FromF64Mode {
+    /// Converts a finite `value` of either sign, or returns `None` if it does
+    /// not fit in a `Decimal`.
+    pub(crate) fn to_decimal(self, value: f64) -> Option<Decimal> {
+        match self {
+            FromF64Mode::ShortestRoundTrip => shortest(value),
+            FromF64Mode::FullExpansion => Decimal::from_f64_retain(value),
+            FromF64Mode::SignificantDigits(digits) => {
+                round_significant(shortest(value)?, digits).map(|decimal| decimal.normalize())
+            }
+            FromF64Mode::DecimalPlaces(places) => Some(shortest(value)?.round_dp(places)),
+        }
+    }
+
+    /// Whether the mode keeps every digit it can, as opposed to rounding on
+    /// request. Only such modes report underflow to zero as `PrecisionLoss`.
+    fn preserves_digits(self) -> bool {
+        matches!(
+            self,
+            FromF64Mode::ShortestRoundTrip | FromF64Mode::FullExpansion
+        )
+    }
+}
+
+/// The shortest round-trip digits of `value`, rounded to 28 decimal places.
+fn shortest(value: f64) -> Option<Decimal> {
+    if !value.is_finite() {
+        return None;
+    }
+    // `Display` for `f64` prints the shortest digits that round-trip, and
+    // never uses an exponent.
+    Decimal::from_str(&value.to_string()).ok()
+}
+
+impl Positive {
+    /// Creates a new `Positive` value from an `f64`, converting it with `mode`.
+    ///
+    /// Validation follows [`Positive::new`], which is this method with
+    /// [`FromF64Mode::default`].
+    ///
+    /// # Errors
+    ///
+    /// The same errors as [`Positive::new`], and `InvalidPrecision` for
+    /// `FromF64Mode::SignificantDigits(0)`. A value that a rounding mode
+    /// rounds down to zero is zero, not `PrecisionLoss`.
+    pub fn from_f64_with(value: f64, mode: FromF64Mode) -> PositiveResult<Self> {
+        Positive::new_decimal(to_decimal_with(value, mode)?)
+    }
+}
+
+/// Converts `value` with `mode`, classifying every failure.
+fn to_decimal_with(value: f64, mode: FromF64Mode) -> PositiveResult<Decimal> {
+    if mode == FromF64Mode::SignificantDigits(0) {
+        return Err(PositiveError::invalid_precision(
+            0,
+            "at least one significant digit is required",
+        ));
+    }
+    if let Some(zero) = classify(value)? {
+        return Ok(zero);
+    }
+    match mode.to_decimal(value) {
+        Some(decimal) if decimal.is_zero() && mode.preserves_digits() => Err(precision_loss(
+            value,
+            "is smaller than the smallest Decimal (1e-28)",
+        )),
+        Some(decimal) => Ok(decimal),
+        None => Err(PositiveError::conversion(
+            "f64",
+            "Positive",
+            PositiveErrorKind::Overflow,
+            &format!("{value} is too large for Decimal"),
+        )),
+    }
+}
+
+/// Converts `value` to the `Decimal` with the same shortest round-trip digits.
+///
+/// Fails with `PrecisionLoss` when those digits need more than 28 decimal
+/// places or more than 96 bits of mantissa.
+pub(crate) fn to_decimal_exact(value: f64) -> PositiveResult<Decimal> {
+    if let Some(zero) = classify(value)? {
+        return Ok(zero);
+    }
+    Decimal::from_str_exact(&value.to_string())
+        .ok()
+        .filter(|decimal| !decimal.is_zero())
+        .ok_or_else(|| precision_loss(value, "cannot be represented exactly as a Decimal"))
+}
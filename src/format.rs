@@ -0,0 +1,172 @@
+/******************************************************************************
+   Author: Joaquín Béjar García
+   Email: jb@taunais.com
+   Date: 18/10/26
+******************************************************************************/
+
+//! `Display`, `Debug`, `LowerExp` and `UpperExp` for `Positive`.
+//!
+//! Every impl works on the `Decimal` digits directly, never through `f64`,
+//! and honours the standard `Formatter` options:
+//!
+//! * width, fill and alignment (`{:>12}`, `{:*^9}`), including sign-aware
+//!   zero padding (`{:08}`)
+//! * `+`, which prints a leading plus sign
+//! * precision, which rounds half to even: on `Display` and `Debug` to that
+//!   many decimal places, like [`Positive::format_fixed_places`] (`{:.2}` of
+//!   `4.578` is `4.58`), and on `{:e}` and `{:E}` to that many digits after
+//!   the leading one, like `f64` (`{:.0e}` of `9.9` is `1e1`)
+//! * `#` on `Display`, which keeps the stored scale (`{:#}` of `1.50` is
+//!   `1.50` where `{}` is `1.5`)
+//!
+//! `Debug` always keeps the stored scale.
+
+use crate::Positive;
+use rust_decimal::{Decimal, RoundingStrategy};
+use std::fmt;
+
+/// The digits of `value`'s magnitude, split at the decimal point.
+pub(crate) fn split_digits(value: Decimal) -> (String, String) {
+    let digits = value.mantissa().unsigned_abs().to_string();
+    let scale = value.scale() as usize;
+    if digits.len() > scale {
+        let (integer, fraction) = digits.split_at(digits.len() - scale);
+        (integer.to_string(), fraction.to_string())
+    } else {
+        ("0".to_string(), format!("{digits:0>scale$}"))
+    }
+}
+
+/// The power of ten of `value`'s leading digit, or `None` for zero.
+///
+/// `1234.5` has magnitude 3 and `0.012` has magnitude -2. Computed from the
+/// digits, so it is exact where `log10().floor()` may not be.
+pub(crate) fn magnitude(value: Decimal) -> Option<i32> {
+    let mantissa = value.mantissa().unsigned_abs();
+    if mantissa == 0 {
+        return None;
+    }
+    Some(mantissa.ilog10() as i32 - value.scale() as i32)
+}
+
+/// Joins integer and fraction digits, omitting the point for no fraction.
+fn join(integer: &str, fraction: &str) -> String {
+    if fraction.is_empty() {
+        integer.to_string()
+    } else {
+        format!("{integer}.{fraction}")
+    }
+}
+
+/// `value` with exactly `places` decimal places, rounding half to even and
+/// padding with zeros.
+pub(crate) fn fixed(value: Decimal, places: usize) -> String {
+    let dp = u32::try_from(places).unwrap_or(u32::MAX);
+    let value = value.round_dp_with_strategy(dp, RoundingStrategy::MidpointNearestEven);
+    let (integer, fraction) = split_digits(value);
+    let fraction = if fraction.len() >= places {
+        fraction[..places].to_string()
+    } else {
+        format!("{fraction:0<places$}")
+    };
+    join(&integer, &fraction)
+}
+
+/// `value` with its stored scale.
+fn full(value: Decimal) -> String {
+    let (integer, fraction) = split_digits(value);
+    join(&integer, &fraction)
+}
+
+/// `value` without trailing fractional zeros.
+fn trimmed(value: Decimal) -> String {
+    let (integer, fraction) = split_digits(value);
+    join(&integer, fraction.trim_end_matches('0'))
+}
+
+/// The digits of `mantissa` rounded half to even to `keep` significant
+/// digits, and whether rounding carried into a new leading digit.
+///
+/// Works on the integer mantissa, so it cannot overflow where rounding the
+/// `Decimal` itself (e.g. `Decimal::MAX` to one digit) would.
+fn round_digits(mantissa: u128, keep: usize) -> (String, bool) {
+    let digits = mantissa.to_string();
+    let Some(drop) = digits.len().checked_sub(keep).filter(|drop| *drop > 0) else {
+        return (digits, false);
+    };
+    let divisor = 10_u128.pow(drop as u32);
+    let (quotient, remainder) = (mantissa / divisor, mantissa % divisor);
+    let half = divisor / 2;
+    let rounds_up = remainder > half || (remainder == half && !quotient.is_multiple_of(2));
+    let rounded = (quotient + u128::from(rounds_up)).to_string();
+    let carried = rounded.len() > keep;
+    (rounded, carried)
+}
+
+/// `value` in scientific notation with the given exponent marker, rounded
+/// half to even to `precision` fractional digits.
+fn scientific(value: Decimal, precision: Option<usize>, marker: char) -> String {
+    let Some(mut exponent) = magnitude(value) else {
+        let fraction = "0".repeat(precision.unwrap_or(0));
+        return format!("{}{marker}0", join("0", &fraction));
+    };
+    let mantissa = value.mantissa().unsigned_abs();
+    let digits = match precision {
+        Some(places) => {
+            let (digits, carried) = round_digits(mantissa, places.saturating_add(1));
+            exponent += i32::from(carried);
+            digits
+        }
+        None => mantissa.to_string(),
+    };
+    let (lead, rest) = digits.split_at(1);
+    let fraction = match precision {
+        Some(places) if rest.len() >= places => rest[..places].to_string(),
+        Some(places) => format!("{rest:0<places$}"),
+        None => rest.trim_end_matches('0').to_string(),
+    };
+    format!("{}{marker}{exponent}", join(lead, &fraction))
+}
+
+impl fmt::Display for Positive {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let value = *self.as_decimal();
+        let digits = match f.precision() {
+            Some(places) => fixed(value, places),
+            None if f.alternate() => full(value),
+            None => trimmed(value),
+        };
+        f.pad_integral(true, "", &digits)
+    }
+}
+
+impl fmt::Debug for Positive {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let value = *self.as_decimal();
+        let digits = match f.precision() {
+            Some(places) => fixed(value, places),
+            None => full(value),
+        };
+        f.pad_integral(true, "", &digits)
+    }
+}
+
+impl fmt::LowerExp for Positive {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.pad_integral(
+            true,
+            "",
+            &scientific(*self.as_decimal(), f.precision(), 'e'),
+        )
+    }
+}
+
+impl fmt::UpperExp for Positive {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.pad_integral(
+            true,
+            "",
+            &scientific(*self.as_decimal(), f.precision(), 'E'),
+        )
+    }
+}
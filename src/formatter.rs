@@ -0,0 +1,322 @@
+/******************************************************************************
+   Author: Joaquín Béjar García
+   Email: jb@taunais.com
+   Date: 18/10/26
+******************************************************************************/
+
+//! Configurable, locale-style number formatting for `Positive`.
+//!
+//! [`PositiveFormatter`] renders a value with a chosen grouping separator,
+//! decimal separator, group size, fraction digit range, rounding strategy
+//! and prefix/suffix. [`PositiveFormatter::parser`] returns the matching
+//! [`PositiveParser`], which reads that output back.
+
+use crate::Positive;
+use crate::error::{PositiveError, PositiveErrorKind, PositiveResult};
+use crate::format::split_digits;
+use crate::parse::{Grouping, has_non_zero_digit, too_small};
+use crate::positive::MAX_SCALE;
+use rust_decimal::{Decimal, RoundingStrategy};
+use std::str::FromStr;
+
+/// Builder-style formatter for grouped, locale-specific output.
+///
+/// The default formatter prints the value with `.` as decimal separator, no
+/// grouping and no trailing fractional zeros, like `Display`.
+///
+/// # Example
+///
+/// ```rust
+/// use positive::{PositiveFormatter, pos_or_panic};
+///
+/// let value = pos_or_panic!(1234567.891);
+///
+/// let us = PositiveFormatter::new()
+///     .grouping_separator(',')
+///     .fraction_digits(2, 2)
+///     .prefix("$");
+/// assert_eq!(us.format(&value), "$1,234,567.89");
+///
+/// let eu = PositiveFormatter::new()
+///     .grouping_separator('.')
+///     .decimal_separator(',')
+///     .fraction_digits(2, 2)
+///     .suffix(" €");
+/// assert_eq!(eu.format(&value), "1.234.567,89 €");
+///
+/// let parser = eu.parser().unwrap();
+/// assert_eq!(parser.parse("1.234.567,89 €").unwrap(), pos_or_panic!(1234567.89));
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PositiveFormatter {
+    grouping_separator: Option<char>,
+    decimal_separator: char,
+    group_size: usize,
+    min_fraction_digits: u32,
+    max_fraction_digits: u32,
+    rounding: RoundingStrategy,
+    prefix: String,
+    suffix: String,
+}
+
+impl Default for PositiveFormatter {
+    fn default() -> Self {
+        PositiveFormatter {
+            grouping_separator: None,
+            decimal_separator: '.',
+            group_size: 3,
+            min_fraction_digits: 0,
+            max_fraction_digits: MAX_SCALE,
+            rounding: RoundingStrategy::MidpointNearestEven,
+            prefix: String::new(),
+            suffix: String::new(),
+        }
+    }
+}
+
+impl PositiveFormatter {
+    /// Creates a formatter with the default settings.
+    #[must_use]
+    pub fn new() -> Self {
+        PositiveFormatter::default()
+    }
+
+    /// Separates groups of integer digits with `separator`.
+    #[must_use]
+    pub fn grouping_separator(mut self, separator: char) -> Self {
+        self.grouping_separator = Some(separator);
+        self
+    }
+
+    /// Separates the integer and fractional digits with `separator`.
+    #[must_use]
+    pub fn decimal_separator(mut self, separator: char) -> Self {
+        self.decimal_separator = separator;
+        self
+    }
+
+    /// Sets how many integer digits form a group (3 by default). A size of
+    /// zero disables grouping.
+    #[must_use]
+    pub fn group_size(mut self, size: usize) -> Self {
+        self.group_size = size;
+        self
+    }
+
+    /// Prints at least `min` and at most `max` fractional digits.
+    ///
+    /// Values with more digits are rounded to `max` with the rounding
+    /// strategy; values with fewer are padded with zeros to `min`. Trailing
+    /// zeros beyond `min` are dropped. `max` is capped at 28 and raised to
+    /// `min` if smaller.
+    #[must_use]
+    pub fn fraction_digits(mut self, min: u32, max: u32) -> Self {
+        self.min_fraction_digits = min;
+        self.max_fraction_digits = max.max(min);
+        self
+    }
+
+    /// Sets the strategy used to round to the maximum fraction digits
+    /// (half to even by default, like [`Positive::round_to`]).
+    #[must_use]
+    pub fn rounding(mut self, strategy: RoundingStrategy) -> Self {
+        self.rounding = strategy;
+        self
+    }
+
+    /// Prints `prefix` before the number, e.g. a currency symbol.
+    #[must_use]
+    pub fn prefix(mut self, prefix: &str) -> Self {
+        self.prefix = prefix.to_string();
+        self
+    }
+
+    /// Prints `suffix` after the number, e.g. a unit.
+    #[must_use]
+    pub fn suffix(mut self, suffix: &str) -> Self {
+        self.suffix = suffix.to_string();
+        self
+    }
+
+    /// Formats `value` with these settings.
+    #[must_use]
+    pub fn format(&self, value: &Positive) -> String {
+        let max = self.max_fraction_digits.min(MAX_SCALE);
+        let rounded = value
+            .as_decimal()
+            .round_dp_with_strategy(max, self.rounding);
+        let (integer, fraction) = split_digits(rounded);
+
+        let min = self.min_fraction_digits as usize;
+        let mut fraction = fraction.trim_end_matches('0').to_string();
+        if fraction.len() < min {
+            fraction.push_str(&"0".repeat(min - fraction.len()));
+        }
+
+        let mut output = self.prefix.clone();
+        output.push_str(&self.group(&integer));
+        if !fraction.is_empty() {
+            output.push(self.decimal_separator);
+            output.push_str(&fraction);
+        }
+        output.push_str(&self.suffix);
+        output
+    }
+
+    /// Inserts the grouping separator into a string of integer digits.
+    fn group(&self, integer: &str) -> String {
+        let separator = match self.grouping_separator {
+            Some(separator) if self.group_size > 0 => separator,
+            _ => return integer.to_string(),
+        };
+        let mut grouped = String::with_capacity(integer.len() * 2);
+        for (index, digit) in integer.chars().enumerate() {
+            if index > 0 && (integer.len() - index).is_multiple_of(self.group_size) {
+                grouped.push(separator);
+            }
+            grouped.push(digit);
+        }
+        grouped
+    }
+
+    /// Returns a parser that reads this formatter's output.
+    ///
+    /// # Errors
+    ///
+    /// Returns `PositiveError::InvalidValue` with no value and kind
+    /// `PositiveErrorKind::Other` if the output would be ambiguous: the two
+    /// separators are equal, either one is an ASCII digit, or the prefix
+    /// or suffix contains a digit or a separator (a suffix of `"5"` would
+    /// print 12 as `125`).
+    pub fn parser(&self) -> PositiveResult<PositiveParser> {
+        let separators = [Some(self.decimal_separator), self.grouping_separator];
+        let is_separator = |c: char| separators.contains(&Some(c));
+        if separators.iter().flatten().any(char::is_ascii_digit) {
+            return Err(ambiguous("separators must not be digits"));
+        }
+        if self.grouping_separator == Some(self.decimal_separator) {
+            return Err(ambiguous("grouping and decimal separators must differ"));
+        }
+        let affixes = [&self.prefix, &self.suffix];
+        if affixes
+            .iter()
+            .flat_map(|affix| affix.chars())
+            .any(|c| c.is_ascii_digit() || is_separator(c))
+        {
+            return Err(ambiguous(
+                "prefix and suffix must not contain digits or separators",
+            ));
+        }
+        Ok(PositiveParser {
+            grouping_separator: self.grouping_separator.filter(|_| self.group_size > 0),
+            decimal_separator: self.decimal_separator,
+            group_size: self.group_size,
+            prefix: self.prefix.clone(),
+            suffix: self.suffix.clone(),
+        })
+    }
+}
+
+/// The error for a formatter whose output cannot be parsed back.
+fn ambiguous(reason: &str) -> PositiveError {
+    PositiveError::InvalidValue {
+        value: None,
+        kind: PositiveErrorKind::Other,
+        reason: reason.to_string(),
+    }
+}
+
+/// Parser for the output of a [`PositiveFormatter`].
+///
+/// Created by [`PositiveFormatter::parser`]. The prefix and suffix are
+/// optional; grouping separators are optional too, but when present they
+/// must split the integer digits into groups of the configured size. Any
+/// number of fractional digits is accepted.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PositiveParser {
+    grouping_separator: Option<char>,
+    decimal_separator: char,
+    group_size: usize,
+    prefix: String,
+    suffix: String,
+}
+
+impl PositiveParser {
+    /// Parses `input` into a `Positive`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `PositiveError::ParseError` with the byte offset of the first
+    /// rejected character, or if the number does not fit in a `Decimal`.
+    /// Returns `PositiveError::ConversionError` with kind `PrecisionLoss` if a
+    /// non-zero number has so many decimal places that it rounds to zero.
+    /// With the `non-zero` feature, zero returns the same error as
+    /// [`Positive::new_decimal`].
+    pub fn parse(&self, input: &str) -> PositiveResult<Positive> {
+        let start = if input.starts_with(self.prefix.as_str()) {
+            self.prefix.len()
+        } else {
+            0
+        };
+        let end = match input[start..].strip_suffix(self.suffix.as_str()) {
+            Some(body) if !self.suffix.is_empty() => start + body.len(),
+            _ => input.len(),
+        };
+
+        let mut integer = String::new();
+        let mut fraction: Option<String> = None;
+        let mut groups = Grouping::new(self.group_size);
+        for (offset, c) in input[start..end].char_indices() {
+            let position = start + offset;
+            let error = |reason: &str| PositiveError::parse_error(input, position, reason);
+            match (c, fraction.as_mut()) {
+                ('0'..='9', Some(fraction)) => fraction.push(c),
+                ('0'..='9', None) => {
+                    integer.push(c);
+                    groups.digit();
+                }
+                (c, None) if Some(c) == self.grouping_separator => {
+                    if !groups.separator() {
+                        return Err(error("misplaced grouping separator"));
+                    }
+                }
+                (c, None) if c == self.decimal_separator => {
+                    if !groups.is_complete() {
+                        return Err(error("misplaced grouping separator"));
+                    }
+                    fraction = Some(String::new());
+                }
+                (c, _) => return Err(error(&format!("unexpected character '{c}'"))),
+            }
+        }
+        if fraction.is_none() && !groups.is_complete() {
+            return Err(PositiveError::parse_error(
+                input,
+                end,
+                "misplaced grouping separator",
+            ));
+        }
+        let fraction = fraction.unwrap_or_default();
+        if integer.is_empty() && fraction.is_empty() {
+            return Err(PositiveError::parse_error(input, end, "expected a digit"));
+        }
+
+        let mut canonical = if integer.is_empty() {
+            "0".to_string()
+        } else {
+            integer
+        };
+        if !fraction.is_empty() {
+            canonical.push('.');
+            canonical.push_str(&fraction);
+        }
+        // `Decimal` rounds inputs with more than 28 significant digits.
+        let number = Decimal::from_str(&canonical).map_err(|_| {
+            PositiveError::parse_error(input, start, "number does not fit in a Decimal")
+        })?;
+        if number.is_zero() && has_non_zero_digit(&canonical) {
+            return Err(too_small(input));
+        }
+        Positive::new_decimal(number)
+    }
+}
@@ -0,0 +1,287 @@
+/******************************************************************************
+   Author: Joaquín Béjar García
+   Email: jb@taunais.com
+   Date: 18/10/26
+******************************************************************************/
+
+//! Snapping `Positive` values to a grid of fixed increments.
+//!
+//! [`Increment`] (also available as [`TickSize`]) is a validated, non-zero
+//! step such as a venue's tick or lot size. It rounds values onto its grid
+//! and enumerates the grid points around a value. The
+//! [`Positive::round_to_increment`] family wraps it for one-off use.
+//!
+//! All arithmetic is exact: the position on the grid comes from the
+//! `Decimal` remainder, never from a rounded quotient.
+
+use crate::Positive;
+use crate::error::{PositiveError, PositiveResult};
+use crate::positive::positive_result;
+use rust_decimal::{Decimal, RoundingStrategy};
+use std::cmp::Ordering;
+
+/// A non-zero step that defines a grid of valid values: `0`, `step`,
+/// `2 * step`, and so on.
+///
+/// # Example
+///
+/// ```rust
+/// use positive::{RoundingStrategy, TickSize, pos_or_panic};
+///
+/// let tick = TickSize::new(pos_or_panic!(0.05)).unwrap();
+/// let price = pos_or_panic!(101.23);
+///
+/// assert_eq!(tick.floor(price).unwrap(), pos_or_panic!(101.2));
+/// assert_eq!(tick.ceil(price).unwrap(), pos_or_panic!(101.25));
+/// assert_eq!(
+///     tick.round(price, RoundingStrategy::MidpointNearestEven).unwrap(),
+///     pos_or_panic!(101.25)
+/// );
+/// assert!(!tick.is_aligned(price));
+///
+/// let above: Vec<_> = tick.points_above(price).take(2).collect();
+/// assert_eq!(above, [pos_or_panic!(101.25), pos_or_panic!(101.3)]);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Increment(Positive);
+
+/// An [`Increment`] used as the minimum price movement of an instrument.
+pub type TickSize = Increment;
+
+impl Increment {
+    /// Creates an increment of `step`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `PositiveError::OutOfBounds` with kind `Zero` if `step` is
+    /// zero.
+    pub fn new(step: Positive) -> PositiveResult<Self> {
+        if step.is_zero() {
+            return Err(PositiveError::not_positive(Decimal::ZERO));
+        }
+        Ok(Increment(step))
+    }
+
+    /// Returns the step.
+    #[must_use]
+    pub fn step(&self) -> Positive {
+        self.0
+    }
+
+    /// Returns whether `value` lies exactly on the grid.
+    #[must_use]
+    pub fn is_aligned(&self, value: Positive) -> bool {
+        self.remainder(value).is_zero()
+    }
+
+    /// Rounds `value` to a grid point with the given strategy.
+    ///
+    /// # Errors
+    ///
+    /// Returns `PositiveError::ArithmeticError` if rounding up overflows, or
+    /// with the `non-zero` feature if the value rounds to zero.
+    pub fn round(&self, value: Positive, strategy: RoundingStrategy) -> PositiveResult<Positive> {
+        self.snap("round_to_increment", value, strategy)
+    }
+
+    /// Rounds `value` down to the grid.
+    ///
+    /// # Errors
+    ///
+    /// With the `non-zero` feature, returns `PositiveError::ArithmeticError`
+    /// if `value` is below the step.
+    pub fn floor(&self, value: Positive) -> PositiveResult<Positive> {
+        self.snap(
+            "floor_to_increment",
+            value,
+            RoundingStrategy::ToNegativeInfinity,
+        )
+    }
+
+    /// Rounds `value` up to the grid.
+    ///
+    /// # Errors
+    ///
+    /// Returns `PositiveError::ArithmeticError` if the grid point above
+    /// `value` overflows `Decimal`.
+    pub fn ceil(&self, value: Positive) -> PositiveResult<Positive> {
+        self.snap(
+            "ceil_to_increment",
+            value,
+            RoundingStrategy::ToPositiveInfinity,
+        )
+    }
+
+    /// Returns the first grid point strictly above `value`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `PositiveError::ArithmeticError` if it overflows `Decimal`.
+    pub fn next_above(&self, value: Positive) -> PositiveResult<Positive> {
+        let below = value.as_decimal() - self.remainder(value);
+        positive_result("next_above", self.step_up(below))
+    }
+
+    /// Returns the last grid point strictly below `value`, or `None` if
+    /// there is none (with the `non-zero` feature, zero does not count).
+    #[must_use]
+    pub fn next_below(&self, value: Positive) -> Option<Positive> {
+        let remainder = self.remainder(value);
+        let below = if remainder.is_zero() {
+            self.step_down(*value.as_decimal())?
+        } else {
+            value.as_decimal() - remainder
+        };
+        Positive::new_decimal(below).ok()
+    }
+
+    /// Iterates over the grid points strictly above `value`, in ascending
+    /// order, until they no longer fit exactly in a `Decimal`.
+    pub fn points_above(&self, value: Positive) -> impl Iterator<Item = Positive> {
+        let increment = *self;
+        std::iter::successors(increment.next_above(value).ok(), move |point| {
+            increment.next_above(*point).ok()
+        })
+    }
+
+    /// Iterates over the grid points strictly below `value`, in descending
+    /// order, down to the lowest valid one or the first that does not fit
+    /// exactly in a `Decimal`.
+    pub fn points_below(&self, value: Positive) -> impl Iterator<Item = Positive> {
+        let increment = *self;
+        std::iter::successors(increment.next_below(value), move |point| {
+            increment.next_below(*point)
+        })
+    }
+
+    /// `value` modulo the step. Exact, as the step is non-zero.
+    fn remainder(&self, value: Positive) -> Decimal {
+        value.as_decimal() % self.0.as_decimal()
+    }
+
+    /// The grid point after `point`, or `None` if it overflows or `Decimal`
+    /// would have to round it, as for `0.5` steps near `Decimal::MAX`.
+    fn step_up(&self, point: Decimal) -> Option<Decimal> {
+        let step = *self.0.as_decimal();
+        let next = point.checked_add(step)?;
+        (next > point && next - step == point).then_some(next)
+    }
+
+    /// The grid point before `point`, or `None` if `Decimal` would have to
+    /// round it. May be negative.
+    fn step_down(&self, point: Decimal) -> Option<Decimal> {
+        let step = *self.0.as_decimal();
+        let previous = point.checked_sub(step)?;
+        (previous < point && previous + step == point).then_some(previous)
+    }
+
+    /// Rounds `value` to the grid point below or above it.
+    ///
+    /// Whether to round up is decided by applying `strategy` to a stand-in
+    /// number with the same parity and the same position relative to the
+    /// midpoint as `value / step`, so every strategy behaves as it does for
+    /// `Decimal::round_dp_with_strategy`.
+    fn snap(
+        &self,
+        operation: &str,
+        value: Positive,
+        strategy: RoundingStrategy,
+    ) -> PositiveResult<Positive> {
+        let step = *self.0.as_decimal();
+        let remainder = self.remainder(value);
+        let below = value.as_decimal() - remainder;
+        if remainder.is_zero() {
+            return positive_result(operation, Some(below));
+        }
+
+        let odd = match step.checked_mul(Decimal::TWO) {
+            Some(double) => !(below % double).is_zero(),
+            // A step above half of `Decimal::MAX` fits at most once.
+            None => !below.is_zero(),
+        };
+        // Compares `remainder` with `step / 2` without overflowing or
+        // rounding.
+        let position = match remainder.cmp(&(step - remainder)) {
+            Ordering::Less => Decimal::new(25, 2),
+            Ordering::Equal => Decimal::new(5, 1),
+            Ordering::Greater => Decimal::new(75, 2),
+        };
+        let parity = if odd { Decimal::ONE } else { Decimal::ZERO };
+        let rounds_up = (parity + position).round_dp_with_strategy(0, strategy) > parity;
+
+        if rounds_up {
+            positive_result(operation, self.step_up(below))
+        } else {
+            positive_result(operation, Some(below))
+        }
+    }
+}
+
+impl TryFrom<Positive> for Increment {
+    type Error = PositiveError;
+
+    fn try_from(step: Positive) -> PositiveResult<Self> {
+        Increment::new(step)
+    }
+}
+
+impl From<Increment> for Positive {
+    fn from(increment: Increment) -> Self {
+        increment.0
+    }
+}
+
+impl Positive {
+    /// Rounds the value to a multiple of `step` with the given strategy.
+    ///
+    /// Use an [`Increment`] to reuse a validated step.
+    ///
+    /// # Errors
+    ///
+    /// Returns `PositiveError::OutOfBounds` if `step` is zero, and
+    /// `PositiveError::ArithmeticError` if rounding up overflows or, with the
+    /// `non-zero` feature, the value rounds to zero.
+    ///
+    /// ```rust
+    /// use positive::{RoundingStrategy, pos_or_panic};
+    ///
+    /// let value = pos_or_panic!(1.375);
+    /// let step = pos_or_panic!(0.25);
+    /// assert_eq!(
+    ///     value.round_to_increment(step, RoundingStrategy::MidpointNearestEven).unwrap(),
+    ///     pos_or_panic!(1.5)
+    /// );
+    /// assert_eq!(
+    ///     value.round_to_increment(step, RoundingStrategy::MidpointTowardZero).unwrap(),
+    ///     pos_or_panic!(1.25)
+    /// );
+    /// ```
+    pub fn round_to_increment(
+        &self,
+        step: Positive,
+        strategy: RoundingStrategy,
+    ) -> PositiveResult<Positive> {
+        Increment::new(step)?.round(*self, strategy)
+    }
+
+    /// Rounds the value down to a multiple of `step`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `PositiveError::OutOfBounds` if `step` is zero and, with the
+    /// `non-zero` feature, `PositiveError::ArithmeticError` if the value is
+    /// below `step`.
+    pub fn floor_to_increment(&self, step: Positive) -> PositiveResult<Positive> {
+        Increment::new(step)?.floor(*self)
+    }
+
+    /// Rounds the value up to a multiple of `step`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `PositiveError::OutOfBounds` if `step` is zero, and
+    /// `PositiveError::ArithmeticError` if the result overflows.
+    pub fn ceil_to_increment(&self, step: Positive) -> PositiveResult<Positive> {
+        Increment::new(step)?.ceil(*self)
+    }
+}
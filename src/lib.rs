@@ -25,6 +25,9 @@
 //! - **Predefined Constants**: Common numeric values (0-10, multiples of 5/100/1000, PI, E, etc.)
 //! - **Convenient Macros**: `pos!`, `pos_or_panic!`, `spos!` for easy value creation
 //! - **Prelude Module**: Simple imports with `use positive::prelude::*;`
+//! - **Locale Formatting**: `PositiveFormatter` with grouping, separators, rounding and prefix/suffix, plus a matching `PositiveParser`
+//! - **Tick and Lot Sizes**: Snap values to a validated `Increment`/`TickSize` grid with any rounding strategy
+//! - **Compact Notation**: `1.2K`, `3.45M`, `12.5bn`, SI prefixes and engineering notation with configurable significant digits
 //! - **Serde Support**: Full serialization/deserialization support for JSON and other formats
 //! - **Approx Support**: Approximate equality comparisons for floating-point tolerance
 //! - **Checked Operations**: Safe arithmetic operations that return `Result` instead of panicking
@@ -36,21 +39,21 @@
 //!
 //! ```toml
 //! [dependencies]
-//! positive = "0.4"
+//! positive = "0.5"
 //! ```
 //!
 //! To require strictly positive values (excluding zero):
 //!
 //! ```toml
 //! [dependencies]
-//! positive = { version = "0.4", features = ["non-zero"] }
+//! positive = { version = "0.5", features = ["non-zero"] }
 //! ```
 //!
 //! To enable OpenAPI schema support:
 //!
 //! ```toml
 //! [dependencies]
-//! positive = { version = "0.4", features = ["utoipa"] }
+//! positive = { version = "0.5", features = ["utoipa"] }
 //! ```
 //!
 //! ## Quick Start
@@ -84,15 +87,26 @@
 //! ### Creation
 //!
 //! ```rust
-//! use positive::{Positive, pos, pos_or_panic, spos};
+//! use positive::{FromF64Mode, ParseOptions, Positive, pos, pos_or_panic, spos};
 //! use rust_decimal::Decimal;
 //!
 //! // From f64
 //! let p = Positive::new(5.0).unwrap();
+//! let p = Positive::new_exact(0.1 + 0.2).unwrap(); // 0.30000000000000004, or Err if digits would be lost
+//! let p = Positive::from_f64_with(2.675, FromF64Mode::DecimalPlaces(2)).unwrap(); // 2.68
 //!
 //! // From Decimal
 //! let p = Positive::new_decimal(Decimal::ONE).unwrap();
 //!
+//! // From integers, exactly (`From` for unsigned and `NonZero` unsigned types)
+//! let p = Positive::try_from(9_007_199_254_740_993_u64).unwrap(); // 2^53 + 1, no f64 rounding
+//! let p = Positive::try_from(-3_i64);        // Err: negative
+//! let p = Positive::try_from(u128::MAX);     // Err: above Decimal::MAX
+//!
+//! // From strings (errors are `PositiveError::ParseError` with the failing position)
+//! let p: Positive = "12.50".parse().unwrap();
+//! let p = Positive::from_str_with("1,250.5", ParseOptions::all()).unwrap(); // Also `%`, `bp`, `1e3`, `1_000`
+//!
 //! // Using macros
 //! let p = pos!(5.0);           // Returns Result<Positive, PositiveError>
 //! let p = pos_or_panic!(5.0);  // Panics on invalid input
@@ -131,22 +145,31 @@
 //!
 //! // Special values
 //! let epsilon = EPSILON;           // Small tolerance for comparisons
-//! let inf = Positive::INFINITY;    // Maximum value
+//! let inf = EXT_INFINITY;          // Positive infinity (a `PositiveExt`)
+//! let min = Positive::MIN;         // Lower saturation point (0, or 1e-28 with `non-zero`)
+//! let max = Positive::MAX;         // Upper saturation point (Decimal::MAX)
 //! ```
 //!
 //! ### Conversions
 //!
 //! ```rust
-//! use positive::pos_or_panic;
+//! use positive::{RoundingStrategy, pos_or_panic};
 //!
 //! let p = pos_or_panic!(5.5);
 //!
 //! let f: f64 = p.to_f64();              // Panics on failure
 //! let f: Option<f64> = p.to_f64_checked(); // Returns None on failure
 //! let f: f64 = p.to_f64_lossy();        // Returns 0.0 on failure
-//! let i: i64 = p.to_i64();              // To signed integer
-//! let u: u64 = p.to_u64();              // To unsigned integer
+//! let i: i64 = p.to_i64();              // To signed integer (truncates)
+//! let u: u64 = p.to_u64();              // To unsigned integer (truncates)
+//! let u: Option<u8> = p.to_u8_checked(); // Also u16, u32, u128, usize, i128, NonZeroU64, ...
+//! let u = p.to_u64_rounded(RoundingStrategy::MidpointAwayFromZero); // Some(6)
+//! let u = u32::try_from(p);             // Err: 5.5 has a fractional part
+//! let s = p.to_decimal_string();        // Exact Decimal text, keeps the scale
+//! let f: f32 = p.to_f32();              // To f32
 //! let d = p.to_dec();                   // To Decimal
+//! let d = p.into_inner();               // Consume into Decimal
+//! let r = p.as_decimal();               // Borrow the inner Decimal
 //! ```
 //!
 //! ### Arithmetic Operations
@@ -162,16 +185,20 @@
 //! let diff = a - b;       // Subtraction (panics if result < 0)
 //! let prod = a * b;       // Multiplication
 //! let quot = a / b;       // Division
+//! let rem = a % b;        // Remainder
 //!
 //! // Safe operations
 //! let safe_diff = a.checked_sub(&b);    // Returns Result
 //! let safe_quot = a.checked_div(&b);    // Returns Result (handles div by zero)
+//! let safe_sum = a.checked_add(&b);     // Returns Result (handles overflow)
+//! let safe_pow = a.checked_powi(3);     // Also checked_mul, checked_pow*, checked_exp, ...
+//! let (lots, rest) = a.div_rem(&b).unwrap(); // Euclidean quotient and remainder
 //! ```
 //!
 //! ### Mathematical Functions
 //!
 //! ```rust
-//! use positive::pos_or_panic;
+//! use positive::{RoundingStrategy, pos_or_panic};
 //!
 //! let p = pos_or_panic!(16.0);
 //!
@@ -185,6 +212,9 @@
 //! let ceil = p.ceiling();        // Ceiling
 //! let round = p.round();         // Round to nearest integer
 //! let round2 = p.round_to(2);    // Round to 2 decimal places
+//! let half_up = p.round_dp_with_strategy(2, RoundingStrategy::MidpointAwayFromZero);
+//! let sf = p.round_sf(3);        // Round to 3 significant figures
+//! let down = p.floor_to(2);      // Also `ceil_to(2)` and `truncate_to(2)`
 //! ```
 //!
 //! ### Utility Methods
@@ -196,10 +226,48 @@
 //!
 //! let is_zero = p.is_zero();                      // Check if zero
 //! let is_mult = p.is_multiple(2.0);               // Check if multiple of value
+//! let snapped = p.ceil_to_increment(pos_or_panic!(2.0)).unwrap(); // 6.0 (see `TickSize` for reuse)
 //! let clamped = p.clamp(pos_or_panic!(1.0), pos_or_panic!(10.0));   // Clamp between bounds
 //! let min_val = p.min(pos_or_panic!(3.0));                 // Minimum of two values
 //! let max_val = p.max(pos_or_panic!(3.0));                 // Maximum of two values
 //! let formatted = p.format_fixed_places(2);       // Format with fixed decimals
+//! let padded = format!("{p:>8.2}");                // "    5.00": width, fill, `+`, `#` and precision
+//! let sci = format!("{:e}", p * 1000.0);          // "5e3" (also `{:E}`)
+//! let compact = (p * 250.0).format_compact(3);    // "1.25K" (also `format_si`, `format_with_suffixes`)
+//! let eng = (p * 2500.0).format_engineering(3);  // "12.5e3"
+//! ```
+//!
+//! ### Locale Formatting
+//!
+//! `PositiveFormatter` prints grouped, locale-style output and hands out a
+//! matching `PositiveParser` that reads it back:
+//!
+//! ```rust
+//! use positive::{PositiveFormatter, pos_or_panic};
+//!
+//! let eu = PositiveFormatter::new()
+//!     .grouping_separator('.')
+//!     .decimal_separator(',')
+//!     .fraction_digits(2, 2)
+//!     .suffix(" €");
+//! let text = eu.format(&pos_or_panic!(1234567.891));    // "1.234.567,89 €"
+//! let back = eu.parser().unwrap().parse(&text).unwrap(); // 1234567.89
+//! ```
+//!
+//! ### Unbounded Values
+//!
+//! `Positive` is always finite. Limits that may be unbounded use `PositiveExt`,
+//! which adds a true `Infinite` variant with IEEE-like arithmetic:
+//!
+//! ```rust
+//! use positive::{PositiveExt, pos_or_panic};
+//!
+//! let limit = PositiveExt::INFINITY;
+//! let used = pos_or_panic!(250.0);
+//!
+//! let remaining = limit - used;                   // Still infinite
+//! let undefined = limit.checked_sub(&limit);      // Err: inf - inf is undefined
+//! let capped = PositiveExt::from(used) < limit;   // Infinity compares above every value
 //! ```
 //!
 //! ## Error Handling
@@ -210,7 +278,7 @@
 //! use positive::{Positive, PositiveError};
 //!
 //! fn example() -> Result<Positive, PositiveError> {
-//!     let value = Positive::new(-5.0)?;  // Returns Err(OutOfBounds)
+//!     let value = Positive::new(-5.0)?;  // Returns Err(OutOfBounds) with kind Negative
 //!     Ok(value)
 //! }
 //! ```
@@ -219,8 +287,22 @@
 //! - `InvalidValue` - Value cannot be represented as a valid positive decimal
 //! - `ArithmeticError` - Error during mathematical operations
 //! - `ConversionError` - Error when converting between types
-//! - `OutOfBounds` - Value exceeds defined limits
+//! - `OutOfBounds` - Value is negative or exceeds defined limits
 //! - `InvalidPrecision` - Invalid decimal precision settings
+//! - `ParseError` - String input that cannot be parsed (with the failing position)
+//!
+//! Every error exposes `kind()` (a `PositiveErrorKind` such as `Negative`, `Zero`,
+//! `NaN`, `Infinite`, `Overflow`, `DivisionByZero`, `PrecisionLoss` or `Parse`),
+//! `value()` (the offending `Decimal`, when known) and `code()`, a stable
+//! identifier such as `"arithmetic.division_by_zero"`:
+//!
+//! ```rust
+//! use positive::{PositiveErrorKind, pos_or_panic};
+//!
+//! let err = pos_or_panic!(1.0).checked_sub(&pos_or_panic!(2.0)).unwrap_err();
+//! assert_eq!(err.kind(), PositiveErrorKind::Negative);
+//! assert_eq!(err.code(), "arithmetic.negative");
+//! ```
 //!
 //! ## Serialization
 //!
@@ -232,8 +314,30 @@
 //! let p = pos_or_panic!(42.5);
 //! let json = serde_json::to_string(&p).unwrap();  // "42.5"
 //! let parsed: positive::Positive = serde_json::from_str(&json).unwrap();
+//! let quoted: positive::Positive = serde_json::from_str(r#""12.50""#).unwrap();  // Numeric strings too
 //! ```
 //!
+//! The default representation is a JSON number. For records that must reconcile
+//! to the last digit, `positive::serde::as_str` keeps the exact decimal string:
+//!
+//! ```rust
+//! use positive::Positive;
+//! use serde::{Deserialize, Serialize};
+//!
+//! #[derive(Serialize, Deserialize)]
+//! struct Settlement {
+//!     #[serde(with = "positive::serde::as_str")]
+//!     amount: Positive,
+//! }
+//!
+//! let parsed: Settlement = serde_json::from_str(r#"{"amount":"123.4500"}"#).unwrap();
+//! let json = serde_json::to_string(&parsed).unwrap();  // {"amount":"123.4500"}
+//! ```
+//!
+//! The same module provides `as_f64`, `as_minor_units::<SCALE>` and `as_parts`
+//! (`{"mantissa": .., "scale": ..}`), each with `_option` and `_vec` variants for
+//! `Option<Positive>` and `Vec<Positive>` fields.
+//!
 //! ## Use Cases
 //!
 //! - **Financial Applications**: Prices, quantities, fees, rates
@@ -246,15 +350,32 @@
 //! This project is licensed under the MIT License.
 //!
 
+mod accumulator;
+mod compact;
 pub mod constants;
+mod convert;
 pub mod error;
+mod extended;
+mod float;
+mod format;
+mod formatter;
+mod increment;
 #[macro_use]
 pub mod macros;
+mod parse;
 mod positive;
 pub mod prelude;
+pub mod serde;
 mod tests;
-pub use error::{PositiveError, PositiveResult};
+pub use accumulator::{PositiveAccumulator, PositiveIteratorExt};
+pub use compact::SuffixTable;
+pub use error::{PositiveError, PositiveErrorKind, PositiveResult};
+pub use extended::PositiveExt;
+pub use float::FromF64Mode;
+pub use formatter::{PositiveFormatter, PositiveParser};
+pub use increment::{Increment, TickSize};
+pub use parse::ParseOptions;
 pub use positive::{Positive, is_positive, is_valid_positive_value};
 
 /// Re-export rust_decimal for convenience.
-pub use rust_decimal::Decimal;
+pub use rust_decimal::{Decimal, RoundingStrategy};
@@ -14,22 +14,32 @@
 /// Returns `Ok(Positive)` if the value is valid and non-negative,
 /// otherwise returns `Err(PositiveError)`.
 ///
+/// The value is converted like [`Positive::new`](crate::Positive::new). An
+/// optional second argument selects a [`FromF64Mode`](crate::FromF64Mode);
+/// the same form is accepted by `pos_or_panic!` and `spos!`.
+///
 /// # Example
 ///
 /// ```rust
-/// use positive::pos;
+/// use positive::{FromF64Mode, pos};
 ///
 /// let valid = pos!(5.0);
 /// assert!(valid.is_ok());
 ///
 /// let invalid = pos!(-5.0);
 /// assert!(invalid.is_err());
+///
+/// let rounded = pos!(0.1 + 0.2, FromF64Mode::DecimalPlaces(2)).unwrap();
+/// assert_eq!(rounded.to_string(), "0.3");
 /// ```
 #[macro_export]
 macro_rules! pos {
     ($val:expr) => {
         $crate::Positive::new($val)
     };
+    ($val:expr, $mode:expr) => {
+        $crate::Positive::from_f64_with($val, $mode)
+    };
 }
 
 /// Macro for creating a new `Positive` value that panics on invalid input.
@@ -56,6 +66,9 @@ macro_rules! pos_or_panic {
     ($val:expr) => {
         $crate::Positive::new($val).expect("Failed to create Positive value")
     };
+    ($val:expr, $mode:expr) => {
+        $crate::Positive::from_f64_with($val, $mode).expect("Failed to create Positive value")
+    };
 }
 
 /// Macro for creating an optional `Positive` value from the given expression.
@@ -79,4 +92,7 @@ macro_rules! spos {
     ($val:expr) => {
         $crate::Positive::new($val).ok()
     };
+    ($val:expr, $mode:expr) => {
+        $crate::Positive::from_f64_with($val, $mode).ok()
+    };
 }
@@ -0,0 +1,346 @@
+/******************************************************************************
+   Author: Joaquín Béjar García
+   Email: jb@taunais.com
+   Date: 18/10/26
+******************************************************************************/
+
+//! String parsing for `Positive` with opt-in syntax extensions.
+//!
+//! [`ParseOptions`] selects which extensions to plain `digits[.digits]`
+//! input are accepted, and [`Positive::from_str_with`] applies them. Every
+//! syntax failure is reported as `PositiveError::ParseError` with the byte
+//! offset where parsing stopped. A non-zero number too small for `Decimal`
+//! is rejected with `PrecisionLoss` instead of silently becoming zero, as
+//! for `f64` conversions.
+
+use crate::Positive;
+use crate::error::{PositiveError, PositiveErrorKind, PositiveResult};
+use crate::positive::shift;
+use rust_decimal::Decimal;
+use std::str::FromStr;
+
+/// Syntax extensions accepted by [`Positive::from_str_with`].
+///
+/// Every option is off by default, which accepts only an optional `-`
+/// sign, digits and a single decimal point.
+///
+/// # Example
+///
+/// ```rust
+/// use positive::{ParseOptions, Positive, pos_or_panic};
+///
+/// let options = ParseOptions {
+///     thousands_separators: true,
+///     suffixes: true,
+///     ..ParseOptions::default()
+/// };
+///
+/// assert_eq!(Positive::from_str_with("1,250.5", options).unwrap(), pos_or_panic!(1250.5));
+/// assert_eq!(Positive::from_str_with("2.5%", options).unwrap(), pos_or_panic!(0.025));
+/// assert_eq!(Positive::from_str_with("15bp", options).unwrap(), pos_or_panic!(0.0015));
+/// assert!(Positive::from_str_with("1e3", options).is_err());
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct ParseOptions {
+    /// Accept a leading `+` sign (`+1.5`).
+    pub leading_plus: bool,
+    /// Accept `_` between digits (`1_000_000`).
+    pub underscores: bool,
+    /// Accept `,` between groups of three integer digits (`1,000,000`).
+    pub thousands_separators: bool,
+    /// Accept an exponent (`1.5e3`, `2E-4`).
+    pub scientific: bool,
+    /// Accept a trailing `%` (divides by 100) or `bp`/`bps` (divides by 10 000).
+    pub suffixes: bool,
+}
+
+impl ParseOptions {
+    /// Options with every extension enabled.
+    #[must_use]
+    pub const fn all() -> Self {
+        ParseOptions {
+            leading_plus: true,
+            underscores: true,
+            thousands_separators: true,
+            scientific: true,
+            suffixes: true,
+        }
+    }
+
+    /// The syntax `Decimal` itself accepts, used by `FromStr for Positive`.
+    pub(crate) const fn decimal() -> Self {
+        ParseOptions {
+            leading_plus: true,
+            underscores: true,
+            thousands_separators: false,
+            scientific: true,
+            suffixes: false,
+        }
+    }
+}
+
+impl Positive {
+    /// Parses a `Positive` from a string with the given syntax extensions.
+    ///
+    /// `FromStr` accepts what `Decimal` accepts (a leading `+`, underscores
+    /// and exponents); this method only accepts what `options` enables.
+    /// Scaling by an exponent or a suffix is exact.
+    ///
+    /// # Errors
+    ///
+    /// Returns `PositiveError::ParseError` with the byte offset of the first
+    /// rejected character, or if the number does not fit in a `Decimal`.
+    /// Returns `PositiveError::ConversionError` with kind `PrecisionLoss` if a
+    /// non-zero number would round to zero. A well-formed but invalid value
+    /// (e.g. negative) returns the same error as [`Positive::new_decimal`].
+    pub fn from_str_with(input: &str, options: ParseOptions) -> PositiveResult<Self> {
+        let number = Scanner::new(input, options).scan()?;
+        Positive::new_decimal(number)
+    }
+}
+
+impl FromStr for Positive {
+    type Err = PositiveError;
+
+    /// Parses the syntax `Decimal` accepts: an optional sign, digits with
+    /// optional underscores, a decimal point and an optional exponent.
+    ///
+    /// Use [`Positive::from_str_with`] for thousands separators and
+    /// `%`/`bp` suffixes, or to reject the extensions accepted here.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Positive::from_str_with(s, ParseOptions::decimal())
+    }
+}
+
+/// Single-pass scanner that validates the input and collects its parts.
+struct Scanner<'a> {
+    input: &'a str,
+    bytes: &'a [u8],
+    position: usize,
+    options: ParseOptions,
+}
+
+impl<'a> Scanner<'a> {
+    fn new(input: &'a str, options: ParseOptions) -> Self {
+        Scanner {
+            input,
+            bytes: input.as_bytes(),
+            position: 0,
+            options,
+        }
+    }
+
+    fn peek(&self) -> Option<u8> {
+        self.bytes.get(self.position).copied()
+    }
+
+    fn error(&self, reason: &str) -> PositiveError {
+        PositiveError::parse_error(self.input, self.position, reason)
+    }
+
+    fn unexpected(&self) -> PositiveError {
+        match self.input[self.position..].chars().next() {
+            Some(c) => self.error(&format!("unexpected character '{c}'")),
+            None => self.error("unexpected end of input"),
+        }
+    }
+
+    fn scan(mut self) -> PositiveResult<Decimal> {
+        if self.input.is_empty() {
+            return Err(self.error("empty input"));
+        }
+
+        let negative = match self.peek() {
+            Some(b'-') => {
+                self.position += 1;
+                true
+            }
+            Some(b'+') if self.options.leading_plus => {
+                self.position += 1;
+                false
+            }
+            Some(b'+') => return Err(self.error("a leading '+' is not enabled")),
+            _ => false,
+        };
+
+        let start = self.position;
+        let mut digits = String::new();
+        let integer_digits = self.scan_digits(&mut digits, true)?;
+        let mut fraction_digits = 0;
+        if self.peek() == Some(b'.') {
+            self.position += 1;
+            fraction_digits = self.scan_digits(&mut digits, false)?;
+        }
+        if integer_digits + fraction_digits == 0 {
+            return Err(self.error("expected a digit"));
+        }
+
+        let mut exponent = 0_i64;
+        let scale_start = self.position;
+        if matches!(self.peek(), Some(b'e' | b'E')) {
+            if !self.options.scientific {
+                return Err(self.error("scientific notation is not enabled"));
+            }
+            self.position += 1;
+            exponent = self.scan_exponent()?;
+        }
+
+        let rest = &self.input[self.position..];
+        let suffix_exponent = match rest {
+            "" => 0,
+            "%" | "bp" | "bps" if !self.options.suffixes => {
+                return Err(self.error("suffixes are not enabled"));
+            }
+            "%" => -2,
+            "bp" | "bps" => -4,
+            _ => return Err(self.unexpected()),
+        };
+
+        let (integer, fraction) = digits.split_at(integer_digits);
+        let mut canonical = String::with_capacity(digits.len() + 2);
+        if negative {
+            canonical.push('-');
+        }
+        canonical.push_str(if integer.is_empty() { "0" } else { integer });
+        if !fraction.is_empty() {
+            canonical.push('.');
+            canonical.push_str(fraction);
+        }
+        // `Decimal` rounds inputs with more than 28 significant digits.
+        let number = Decimal::from_str(&canonical)
+            .map_err(|e| PositiveError::parse_error(self.input, start, &e.to_string()))?;
+        let shifted = shift(number, exponent.saturating_add(suffix_exponent)).ok_or_else(|| {
+            PositiveError::parse_error(self.input, scale_start, "number does not fit in a Decimal")
+        })?;
+        // `number` may already have been rounded to zero, so check the digits.
+        if shifted.is_zero() && has_non_zero_digit(&digits) {
+            return Err(too_small(self.input));
+        }
+        Ok(shifted)
+    }
+
+    /// Collects ASCII digits into `digits`, skipping enabled separators, and
+    /// returns how many digits were read.
+    fn scan_digits(&mut self, digits: &mut String, integer: bool) -> PositiveResult<usize> {
+        let mut count = 0;
+        let mut groups = Grouping::new(3);
+        while let Some(byte) = self.peek() {
+            match byte {
+                b'0'..=b'9' => {
+                    digits.push(char::from(byte));
+                    count += 1;
+                    groups.digit();
+                }
+                b'_' if self.options.underscores && count > 0 && self.previous() != Some(b',') => {}
+                b'_' if self.options.underscores => {
+                    return Err(self.error("'_' must follow a digit or another '_'"));
+                }
+                b',' if integer && self.options.thousands_separators => {
+                    // The digit check also rejects a separator after '_'.
+                    if !self.previous().is_some_and(|byte| byte.is_ascii_digit())
+                        || !groups.separator()
+                    {
+                        return Err(self.error("misplaced thousands separator"));
+                    }
+                }
+                _ => break,
+            }
+            self.position += 1;
+        }
+        if !groups.is_complete() {
+            return Err(self.error("thousands separators must split groups of three digits"));
+        }
+        Ok(count)
+    }
+
+    fn previous(&self) -> Option<u8> {
+        self.position.checked_sub(1).map(|index| self.bytes[index])
+    }
+
+    fn scan_exponent(&mut self) -> PositiveResult<i64> {
+        let negative = match self.peek() {
+            Some(b'-') => {
+                self.position += 1;
+                true
+            }
+            Some(b'+') => {
+                self.position += 1;
+                false
+            }
+            _ => false,
+        };
+        let start = self.position;
+        while matches!(self.peek(), Some(b'0'..=b'9')) {
+            self.position += 1;
+        }
+        if start == self.position {
+            return Err(self.error("expected exponent digits"));
+        }
+        let exponent = i64::from_str(&self.input[start..self.position])
+            .map_err(|_| PositiveError::parse_error(self.input, start, "exponent is too large"))?;
+        Ok(if negative { -exponent } else { exponent })
+    }
+}
+
+/// Whether `text` contains a digit other than zero.
+pub(crate) fn has_non_zero_digit(text: &str) -> bool {
+    text.bytes().any(|byte| matches!(byte, b'1'..=b'9'))
+}
+
+/// The error for a non-zero number that rounds to zero in a `Decimal`.
+pub(crate) fn too_small(input: &str) -> PositiveError {
+    PositiveError::conversion(
+        "str",
+        "Positive",
+        PositiveErrorKind::PrecisionLoss,
+        &format!("{input} is too small for Decimal"),
+    )
+}
+
+/// Tracks grouping separators in a run of integer digits, checking that
+/// they split it into groups of `size` digits after a shorter leading group.
+///
+/// Shared by [`Positive::from_str_with`] and
+/// [`PositiveParser`](crate::PositiveParser).
+pub(crate) struct Grouping {
+    size: usize,
+    group: usize,
+    digits: usize,
+    grouped: bool,
+}
+
+impl Grouping {
+    pub(crate) fn new(size: usize) -> Self {
+        Grouping {
+            size,
+            group: 0,
+            digits: 0,
+            grouped: false,
+        }
+    }
+
+    /// Records an integer digit.
+    pub(crate) fn digit(&mut self) {
+        self.digits += 1;
+        self.group += 1;
+    }
+
+    /// Records a separator, returning whether it is allowed here.
+    #[must_use]
+    pub(crate) fn separator(&mut self) -> bool {
+        let allowed = self.digits > 0
+            && if self.grouped {
+                self.group == self.size
+            } else {
+                self.group <= self.size
+            };
+        self.grouped = true;
+        self.group = 0;
+        allowed
+    }
+
+    /// Whether the digits so far end with a complete group.
+    pub(crate) fn is_complete(&self) -> bool {
+        !self.grouped || self.group == self.size
+    }
+}
@@ -7,19 +7,19 @@
 //! Core implementation of the Positive type.
 
 use crate::constants::EPSILON;
-use crate::error::PositiveError;
+use crate::error::{PositiveError, PositiveErrorKind, PositiveResult};
+use crate::float::FromF64Mode;
 use approx::{AbsDiffEq, RelativeEq};
-use num_traits::{FromPrimitive, Pow, ToPrimitive};
-use rust_decimal::{Decimal, MathematicalOps};
+use num_traits::{FromPrimitive, ToPrimitive};
+use rust_decimal::{Decimal, MathematicalOps, RoundingStrategy};
 use rust_decimal_macros::dec;
-use serde::de::Visitor;
+use serde::de::{MapAccess, Unexpected, Visitor};
 use serde::{Deserialize, Deserializer, Serialize, Serializer};
 use std::cmp::{Ordering, PartialEq};
 use std::fmt;
-use std::fmt::Display;
-#[cfg(not(feature = "non-zero"))]
-use std::iter::Sum;
-use std::ops::{Add, AddAssign, Div, Mul, MulAssign, Neg, Sub};
+use std::ops::{
+    Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Rem, RemAssign, Sub, SubAssign,
+};
 use std::str::FromStr;
 
 /// A wrapper type that represents a guaranteed positive decimal value.
@@ -29,9 +29,21 @@ use std::str::FromStr;
 ///
 /// When the `non-zero` feature is enabled, the value must be strictly
 /// greater than zero.
+///
+/// The inner `Decimal` is private, so every `Positive` in circulation was built
+/// through a validating constructor ([`Positive::new`], [`Positive::new_decimal`],
+/// [`Positive::new_const`]) or the explicitly `unsafe` [`Positive::new_unchecked`].
+/// Use [`Positive::into_inner`] or [`Positive::as_decimal`] to read it back.
+///
+/// ```compile_fail
+/// use positive::Positive;
+/// use rust_decimal::Decimal;
+///
+/// let invalid = Positive(Decimal::NEGATIVE_ONE);
+/// ```
 #[derive(PartialEq, Clone, Copy, Hash)]
 #[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
-pub struct Positive(pub Decimal);
+pub struct Positive(Decimal);
 
 /// Returns whether the given decimal value satisfies the positivity constraint.
 ///
@@ -39,32 +51,144 @@ pub struct Positive(pub Decimal);
 /// With the `non-zero` feature, only values > 0 are accepted.
 #[inline]
 #[must_use]
-pub fn is_valid_positive_value(value: Decimal) -> bool {
+pub const fn is_valid_positive_value(value: Decimal) -> bool {
     #[cfg(feature = "non-zero")]
     {
-        value > Decimal::ZERO
+        !value.is_zero() && value.is_sign_positive()
     }
     #[cfg(not(feature = "non-zero"))]
     {
-        value >= Decimal::ZERO
+        value.is_zero() || value.is_sign_positive()
     }
 }
 
-/// Returns the minimum bound for error messages.
+/// Validates the outcome of an arithmetic operation on a `Positive` value.
 ///
-/// Without the `non-zero` feature, the minimum is 0.0.
-/// With the `non-zero` feature, the minimum is the smallest representable
-/// positive f64 value.
-#[inline]
-#[must_use]
-fn min_bound() -> f64 {
-    #[cfg(feature = "non-zero")]
-    {
-        f64::MIN_POSITIVE
+/// `value` is `None` when the underlying `Decimal` operation could not produce
+/// a result (overflow, division by zero, or an undefined result such as the
+/// logarithm of zero).
+pub(crate) fn positive_result(operation: &str, value: Option<Decimal>) -> PositiveResult<Positive> {
+    match value {
+        Some(value) if is_valid_positive_value(value) => Ok(Positive(value)),
+        Some(value) => Err(PositiveError::arithmetic(
+            operation,
+            PositiveErrorKind::of_decimal(value),
+            Some(value),
+            &format!("Resulting value must be positive, got {value}"),
+        )),
+        None => Err(overflow_error(operation)),
+    }
+}
+
+/// Rounds `value` half to even to `digits` significant digits, leaving
+/// values that already fit untouched. `None` if rounding up overflows.
+///
+/// `Decimal::round_sf` returns an out-of-range scale when asked for more
+/// digits than the value has, so that case never reaches it.
+pub(crate) fn round_significant(value: Decimal, digits: u32) -> Option<Decimal> {
+    let mantissa = value.normalize().mantissa().unsigned_abs();
+    if mantissa == 0 || mantissa.ilog10() < digits {
+        return Some(value);
     }
-    #[cfg(not(feature = "non-zero"))]
-    {
-        0.0
+    value.round_sf(digits)
+}
+
+/// Largest scale a `Decimal` can hold, and so the largest power of ten it
+/// can shift by in one step.
+pub(crate) const MAX_SCALE: u32 = Decimal::MAX_SCALE;
+
+/// Multiplies `value` by `10^exponent`, exactly whenever the result fits.
+///
+/// Results that would need more than 28 decimal places are rounded the way
+/// `Decimal` division rounds.
+pub(crate) fn shift(value: Decimal, exponent: i64) -> Option<Decimal> {
+    let scale = i64::from(value.scale()).checked_sub(exponent)?;
+    if (0..=i64::from(MAX_SCALE)).contains(&scale) {
+        return Decimal::try_from_i128_with_scale(value.mantissa(), u32::try_from(scale).ok()?)
+            .ok();
+    }
+    if scale < 0 {
+        let factor = 10_i128.checked_pow(u32::try_from(-scale).ok()?)?;
+        return Decimal::try_from_i128_with_scale(value.mantissa().checked_mul(factor)?, 0).ok();
+    }
+    // Too many decimal places: divide in steps that `Decimal` can represent.
+    let mut result = value;
+    let mut remaining = -exponent;
+    while remaining > 0 && !result.is_zero() {
+        let step = remaining.min(i64::from(MAX_SCALE));
+        let divisor =
+            Decimal::try_from_i128_with_scale(10_i128.pow(u32::try_from(step).ok()?), 0).ok()?;
+        result = result.checked_div(divisor)?;
+        remaining -= step;
+    }
+    Some(result)
+}
+
+/// The error for a `Decimal` operation that produced no result.
+fn overflow_error(operation: &str) -> PositiveError {
+    PositiveError::arithmetic(
+        operation,
+        PositiveErrorKind::Overflow,
+        None,
+        "result overflowed or is undefined",
+    )
+}
+
+/// The error for a division or remainder whose divisor is zero.
+fn division_by_zero(operation: &str) -> PositiveError {
+    PositiveError::arithmetic(
+        operation,
+        PositiveErrorKind::DivisionByZero,
+        None,
+        "division by zero",
+    )
+}
+
+/// Like [`positive_result`], but reports the logarithm of zero as infinite.
+fn log_result(operation: &str, input: Decimal, value: Option<Decimal>) -> PositiveResult<Positive> {
+    if input.is_zero() {
+        return Err(PositiveError::arithmetic(
+            operation,
+            PositiveErrorKind::Infinite,
+            None,
+            "result overflowed or is undefined",
+        ));
+    }
+    positive_result(operation, value)
+}
+
+/// Panicking counterpart of [`positive_result`].
+///
+/// Every operator impl and infallible method on `Positive` funnels its result
+/// through here, so an invalid outcome always panics with the `Display` of the
+/// corresponding `PositiveError::ArithmeticError`.
+#[track_caller]
+fn expect_positive(operation: &str, value: Option<Decimal>) -> Positive {
+    positive_result(operation, value).unwrap_or_else(|e| panic!("{e}"))
+}
+
+/// Clamps the outcome of an arithmetic operation into the valid range.
+///
+/// Values below the positivity constraint saturate at [`Positive::MIN`], and a
+/// failed `Decimal` operation (`None`, i.e. overflow) saturates at
+/// [`Positive::MAX`].
+fn saturate(value: Option<Decimal>) -> Positive {
+    match value {
+        Some(value) if is_valid_positive_value(value) => Positive(value),
+        Some(_) => Positive::MIN,
+        None => Positive::MAX,
+    }
+}
+
+/// Saturating counterpart of [`saturate`] for powers.
+///
+/// A failed `Decimal` power means the result left the representable range;
+/// `shrinking` tells whether it was heading towards zero (saturate at
+/// [`Positive::MIN`]) rather than towards infinity (saturate at [`Positive::MAX`]).
+fn saturate_pow(value: Option<Decimal>, shrinking: bool) -> Positive {
+    match value {
+        None if shrinking => Positive::MIN,
+        value => saturate(value),
     }
 }
 
@@ -177,28 +301,70 @@ impl Positive {
     pub const PI: Positive = crate::constants::PI;
     /// The mathematical constant e (Euler's number) represented as a `Positive` value.
     pub const E: Positive = crate::constants::E;
-    /// Represents the maximum positive value possible (effectively infinity).
-    pub const INFINITY: Positive = crate::constants::INFINITY;
+    /// The smallest value a `Positive` can hold (zero, or `1e-28` with `non-zero`).
+    pub const MIN: Positive = crate::constants::MIN;
+    /// The largest value a `Positive` can hold (`Decimal::MAX`).
+    pub const MAX: Positive = crate::constants::MAX;
+    /// The largest finite value, formerly used as an infinity sentinel.
+    ///
+    /// `Positive` is always finite, so this is the same value as
+    /// [`Positive::MAX`] and behaves like any other finite number.
+    #[deprecated(
+        since = "0.5.0",
+        note = "`Positive` is always finite; use `PositiveExt::INFINITY` for an unbounded value or `Positive::MAX` for the largest finite one"
+    )]
+    pub const INFINITY: Positive = crate::constants::MAX;
 
     /// Creates a new `Positive` value from a 64-bit floating-point number.
     ///
     /// Without the `non-zero` feature, values >= 0 are accepted.
     /// With the `non-zero` feature, only values > 0 are accepted.
+    ///
+    /// The value keeps its shortest round-trip digits
+    /// ([`FromF64Mode::ShortestRoundTrip`](crate::FromF64Mode)); use
+    /// [`Positive::from_f64_with`] to choose another conversion. `-0.0` is
+    /// treated as zero.
+    ///
+    /// # Errors
+    ///
+    /// * `OutOfBounds` with kind `Negative` (or `Zero` with `non-zero`)
+    /// * `ConversionError` with kind `NaN` or `Infinite` for non-finite input
+    /// * `ConversionError` with kind `Overflow` above `Decimal::MAX`
+    /// * `ConversionError` with kind `PrecisionLoss` for non-zero magnitudes
+    ///   below `1e-28`, including every subnormal
     pub fn new(value: f64) -> Result<Self, PositiveError> {
-        let dec = Decimal::from_f64(value);
-        match dec {
-            Some(value) if is_valid_positive_value(value) => Ok(Positive(value)),
-            Some(value) => Err(PositiveError::OutOfBounds {
-                value: value.to_f64().unwrap_or(0.0),
-                min: min_bound(),
-                max: f64::MAX,
-            }),
-            None => Err(PositiveError::ConversionError {
-                from_type: "f64".to_string(),
-                to_type: "Positive".to_string(),
-                reason: "failed to parse Decimal".to_string(),
-            }),
-        }
+        Positive::from_f64_with(value, FromF64Mode::default())
+    }
+
+    /// Creates a new `Positive` value holding exactly the digits of `value`.
+    ///
+    /// The result has the shortest decimal expansion that converts back to
+    /// the identical `f64`, like [`Positive::new`], but digits that do not
+    /// fit in a `Decimal` are an error instead of being rounded.
+    ///
+    /// # Errors
+    ///
+    /// The same errors as [`Positive::new`], and `ConversionError` with kind
+    /// `PrecisionLoss` when those digits do not fit in a `Decimal` (more than
+    /// 28 decimal places or 96 bits of mantissa).
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use positive::{Positive, PositiveErrorKind};
+    ///
+    /// assert_eq!(Positive::new_exact(0.1 + 0.2).unwrap().to_string(), "0.30000000000000004");
+    /// assert_eq!(
+    ///     Positive::new_exact(1.2345678901234567e-20).unwrap_err().kind(),
+    ///     PositiveErrorKind::PrecisionLoss
+    /// );
+    /// assert_eq!(
+    ///     Positive::new_exact(1.5e-30).unwrap_err().kind(),
+    ///     PositiveErrorKind::PrecisionLoss
+    /// );
+    /// ```
+    pub fn new_exact(value: f64) -> Result<Self, PositiveError> {
+        Positive::new_decimal(crate::float::to_decimal_exact(value)?)
     }
 
     /// Creates a new `Positive` value directly from a `Decimal`.
@@ -209,14 +375,77 @@ impl Positive {
         if is_valid_positive_value(value) {
             Ok(Positive(value))
         } else {
-            Err(PositiveError::OutOfBounds {
-                value: value.to_f64().unwrap_or(0.0),
-                min: min_bound(),
-                max: f64::INFINITY,
-            })
+            Err(PositiveError::not_positive(value))
         }
     }
 
+    /// Creates a new `Positive` value from a `Decimal` in a `const` context.
+    ///
+    /// This is the constructor used for the predefined constants. Because the
+    /// check runs at compile time when used in a `const` item, an invalid value
+    /// is rejected by the compiler rather than at runtime.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `value` does not satisfy the positivity constraint (see
+    /// [`is_valid_positive_value`]). Use [`Positive::new_decimal`] for a
+    /// non-panicking alternative.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use positive::Positive;
+    /// use rust_decimal_macros::dec;
+    ///
+    /// const TICK: Positive = Positive::new_const(dec!(0.25));
+    /// assert_eq!(TICK.into_inner(), dec!(0.25));
+    /// ```
+    #[must_use]
+    pub const fn new_const(value: Decimal) -> Self {
+        assert!(
+            is_valid_positive_value(value),
+            "Positive::new_const called with a value that violates the positivity constraint"
+        );
+        Positive(value)
+    }
+
+    /// Wraps a `Decimal` that the caller has already proven to be valid.
+    ///
+    /// Only for operations whose result is valid by construction (for example
+    /// the sum of two `Positive` values). The invariant is still checked in
+    /// debug builds.
+    #[inline]
+    #[must_use]
+    const fn from_valid(value: Decimal) -> Self {
+        debug_assert!(is_valid_positive_value(value));
+        Positive(value)
+    }
+
+    /// Consumes the `Positive` and returns the inner `Decimal` value.
+    #[must_use]
+    pub const fn into_inner(self) -> Decimal {
+        self.0
+    }
+
+    /// Returns a reference to the inner `Decimal` value.
+    #[must_use]
+    pub const fn as_decimal(&self) -> &Decimal {
+        &self.0
+    }
+
+    /// Returns the inner `Decimal` value.
+    ///
+    /// Provided as a migration path for code that used to read the formerly
+    /// public tuple field `.0` directly.
+    #[deprecated(
+        since = "0.5.0",
+        note = "the inner field is private; use `into_inner()` or `as_decimal()` instead"
+    )]
+    #[must_use]
+    pub const fn inner(&self) -> Decimal {
+        self.0
+    }
+
     /// Returns the inner `Decimal` value.
     #[must_use]
     pub fn value(&self) -> Decimal {
@@ -260,63 +489,6 @@ impl Positive {
         self.0.to_f64().unwrap_or(0.0)
     }
 
-    /// Converts the value to a 64-bit signed integer.
-    ///
-    /// # Panics
-    ///
-    /// This method will panic if the conversion fails. Use `to_i64_checked()`
-    /// for a non-panicking alternative.
-    #[must_use]
-    pub fn to_i64(&self) -> i64 {
-        self.0
-            .to_i64()
-            .expect("Decimal to i64 conversion failed - value out of range")
-    }
-
-    /// Converts the value to i64, returning None if conversion fails.
-    #[must_use]
-    pub fn to_i64_checked(&self) -> Option<i64> {
-        self.0.to_i64()
-    }
-
-    /// Converts the inner value to a `u64`.
-    ///
-    /// # Panics
-    ///
-    /// This method will panic if the conversion fails. Use `to_u64_checked()`
-    /// for a non-panicking alternative.
-    #[must_use]
-    pub fn to_u64(&self) -> u64 {
-        self.0
-            .to_u64()
-            .expect("Decimal to u64 conversion failed - value out of range")
-    }
-
-    /// Converts the value to u64, returning None if conversion fails.
-    #[must_use]
-    pub fn to_u64_checked(&self) -> Option<u64> {
-        self.0.to_u64()
-    }
-
-    /// Converts the value to a usize.
-    ///
-    /// # Panics
-    ///
-    /// This method will panic if the conversion fails. Use `to_usize_checked()`
-    /// for a non-panicking alternative.
-    #[must_use]
-    pub fn to_usize(&self) -> usize {
-        self.0
-            .to_usize()
-            .expect("Decimal to usize conversion failed - value out of range")
-    }
-
-    /// Converts the value to usize, returning None if conversion fails.
-    #[must_use]
-    pub fn to_usize_checked(&self) -> Option<usize> {
-        self.0.to_usize()
-    }
-
     /// Returns the maximum of two `Positive` values.
     #[must_use]
     pub fn max(self, other: Positive) -> Positive {
@@ -332,55 +504,66 @@ impl Positive {
     /// Rounds the value down to the nearest integer.
     #[must_use]
     pub fn floor(&self) -> Positive {
-        Positive(self.0.floor())
+        expect_positive("floor", Some(self.0.floor()))
     }
 
     /// Raises this value to an integer power.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the result overflows `Decimal` or a zero value is raised to
+    /// a negative power.
     #[must_use]
     pub fn powi(&self, n: i64) -> Positive {
-        Positive(self.0.powi(n))
+        self.checked_powi(n).unwrap_or_else(|e| panic!("{e}"))
     }
 
     /// Computes the result of raising the current value to the power of the given exponent.
     #[must_use]
     pub fn pow(&self, n: Positive) -> Positive {
-        Positive(self.0.pow(n.to_dec()))
+        expect_positive("pow", self.0.checked_powd(n.0))
     }
 
     /// Raises the current value to the power of `n` using unsigned integer exponentiation.
     #[must_use]
     pub fn powu(&self, n: u64) -> Positive {
-        Positive(self.0.powu(n))
+        expect_positive("powu", self.0.checked_powu(n))
     }
 
     /// Raises this value to a decimal power.
+    ///
+    /// # Panics
+    ///
+    /// Panics where [`Positive::checked_powd`] returns an error, including
+    /// zero raised to a negative power.
     #[must_use]
     pub fn powd(&self, p0: Decimal) -> Positive {
-        Positive(self.0.powd(p0))
+        self.checked_powd(p0).unwrap_or_else(|e| panic!("{e}"))
     }
 
     /// Rounds the value to the nearest integer.
     #[must_use]
     pub fn round(&self) -> Positive {
-        Positive(self.0.round())
+        expect_positive("round", Some(self.0.round()))
     }
 
     /// Rounds the current value to a "nice" number, based on its magnitude.
     #[must_use]
     pub fn round_to_nice_number(&self) -> Positive {
-        let magnitude = self.log10().floor();
-        let ten_pow = Positive::TEN.pow(magnitude);
-        let normalized = self / &ten_pow;
+        let magnitude =
+            crate::format::magnitude(self.0).expect("Unable to calculate log10 for zero");
+        let ten_pow = Decimal::TEN.powi(i64::from(magnitude));
+        let normalized = self.0 / ten_pow;
         let nice_number = if normalized < dec!(1.5) {
-            Positive::ONE
-        } else if normalized < pos_or_panic!(3.0) {
-            Positive::TWO
-        } else if normalized < pos_or_panic!(7.0) {
-            pos_or_panic!(5.0)
+            Decimal::ONE
+        } else if normalized < dec!(3) {
+            Decimal::TWO
+        } else if normalized < dec!(7) {
+            dec!(5)
         } else {
-            Positive::TEN
+            Decimal::TEN
         };
-        nice_number * pos_or_panic!(10.0).powu(magnitude.to_u64())
+        expect_positive("round_to_nice_number", nice_number.checked_mul(ten_pow))
     }
 
     /// Calculates the square root of the value.
@@ -391,39 +574,136 @@ impl Positive {
     /// Use `sqrt_checked()` for a non-panicking alternative.
     #[must_use]
     pub fn sqrt(&self) -> Positive {
-        Positive(self.0.sqrt().expect("Square root calculation failed"))
+        expect_positive("sqrt", self.0.sqrt())
     }
 
     /// Calculates the square root, returning an error if it fails.
     pub fn sqrt_checked(&self) -> Result<Positive, PositiveError> {
-        self.0.sqrt().map(Positive).ok_or_else(|| {
-            PositiveError::arithmetic_error("sqrt", "square root calculation failed")
-        })
+        positive_result("sqrt", self.0.sqrt())
     }
 
     /// Calculates the natural logarithm of the value.
+    ///
+    /// # Panics
+    ///
+    /// The logarithm of a value below one is negative, so this method panics
+    /// for inputs in `[0, 1)` (and for exactly one when the `non-zero` feature
+    /// is enabled).
     #[must_use]
     pub fn ln(&self) -> Positive {
-        Positive(self.0.ln())
+        expect_positive("ln", self.0.checked_ln())
     }
 
     /// Rounds the value to a specified number of decimal places.
     #[must_use]
     pub fn round_to(&self, decimal_places: u32) -> Positive {
-        Positive(self.0.round_dp(decimal_places))
+        expect_positive("round_to", Some(self.0.round_dp(decimal_places)))
+    }
+
+    /// Rounds the value to `decimal_places` with the given strategy.
+    ///
+    /// # Panics
+    ///
+    /// With the `non-zero` feature, panics if the value rounds to zero.
+    ///
+    /// ```rust
+    /// use positive::{RoundingStrategy, pos_or_panic};
+    ///
+    /// let price = pos_or_panic!(2.345);
+    /// assert_eq!(price.round_to(2), pos_or_panic!(2.34));
+    /// assert_eq!(
+    ///     price.round_dp_with_strategy(2, RoundingStrategy::MidpointAwayFromZero),
+    ///     pos_or_panic!(2.35)
+    /// );
+    /// ```
+    #[must_use]
+    pub fn round_dp_with_strategy(
+        &self,
+        decimal_places: u32,
+        strategy: RoundingStrategy,
+    ) -> Positive {
+        expect_positive(
+            "round_dp_with_strategy",
+            Some(self.0.round_dp_with_strategy(decimal_places, strategy)),
+        )
+    }
+
+    /// Rounds the value half to even to `digits` significant figures. A
+    /// `digits` of zero is treated as one.
+    ///
+    /// # Panics
+    ///
+    /// Panics if rounding up overflows `Decimal`, e.g. `Positive::MAX` to
+    /// one significant figure.
+    ///
+    /// ```rust
+    /// use positive::pos_or_panic;
+    ///
+    /// assert_eq!(pos_or_panic!(123456.0).round_sf(3), pos_or_panic!(123000.0));
+    /// assert_eq!(pos_or_panic!(0.0012345).round_sf(2), pos_or_panic!(0.0012));
+    /// ```
+    #[must_use]
+    pub fn round_sf(&self, digits: u32) -> Positive {
+        expect_positive("round_sf", round_significant(self.0, digits.max(1)))
+    }
+
+    /// Rounds the value down to `decimal_places`.
+    ///
+    /// # Panics
+    ///
+    /// With the `non-zero` feature, panics if the value rounds to zero.
+    #[must_use]
+    pub fn floor_to(&self, decimal_places: u32) -> Positive {
+        expect_positive(
+            "floor_to",
+            Some(
+                self.0
+                    .round_dp_with_strategy(decimal_places, RoundingStrategy::ToNegativeInfinity),
+            ),
+        )
+    }
+
+    /// Rounds the value up to `decimal_places`.
+    #[must_use]
+    pub fn ceil_to(&self, decimal_places: u32) -> Positive {
+        expect_positive(
+            "ceil_to",
+            Some(
+                self.0
+                    .round_dp_with_strategy(decimal_places, RoundingStrategy::ToPositiveInfinity),
+            ),
+        )
+    }
+
+    /// Drops the digits beyond `decimal_places`. For a `Positive` this is
+    /// the same as [`Positive::floor_to`].
+    ///
+    /// # Panics
+    ///
+    /// With the `non-zero` feature, panics if the value truncates to zero.
+    #[must_use]
+    pub fn truncate_to(&self, decimal_places: u32) -> Positive {
+        expect_positive("truncate_to", Some(self.0.trunc_with_scale(decimal_places)))
     }
 
     /// Formats the value with a fixed number of decimal places.
+    ///
+    /// The value is rounded half to even, like [`Positive::round_to`], and
+    /// padded with zeros to exactly `decimal_places` digits. This is the same
+    /// as precision in a format string (`{:.2}`).
     #[must_use]
     pub fn format_fixed_places(&self, decimal_places: u32) -> String {
-        let rounded = self.round_to(decimal_places).to_f64();
-        format!("{:.1$}", rounded, decimal_places as usize)
+        crate::format::fixed(self.0, decimal_places as usize)
     }
 
     /// Calculates the exponential function e^x for this value.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the result overflows `Decimal`.
     #[must_use]
     pub fn exp(&self) -> Positive {
-        Positive(self.0.exp())
+        expect_positive("exp", self.0.checked_exp())
     }
 
     /// Clamps the value between a minimum and maximum.
@@ -447,70 +727,313 @@ impl Positive {
     /// Returns the smallest integer greater than or equal to the value.
     #[must_use]
     pub fn ceiling(&self) -> Positive {
-        Positive(self.to_dec().ceil())
+        Positive::from_valid(self.0.ceil())
     }
 
     /// Computes the base-10 logarithm of the value.
+    ///
+    /// # Panics
+    ///
+    /// The logarithm of a value below one is negative, so this method panics
+    /// for inputs in `[0, 1)` (and for exactly one when the `non-zero` feature
+    /// is enabled).
     #[must_use]
     pub fn log10(&self) -> Positive {
-        Positive(self.0.log10())
+        expect_positive("log10", self.0.checked_log10())
     }
 
     /// Subtracts a decimal value, returning zero if the result would be negative.
     ///
-    /// This method is not available when the `non-zero` feature is enabled
-    /// because the result could be zero.
-    #[cfg(not(feature = "non-zero"))]
+    /// When the `non-zero` feature is enabled the result saturates at
+    /// [`Positive::MIN`] instead, since zero is not a valid value.
     #[must_use]
     pub fn sub_or_zero(&self, other: &Decimal) -> Positive {
-        if &self.0 > other {
-            Positive(self.0 - other)
-        } else {
-            Positive(Decimal::ZERO)
-        }
+        saturate(self.0.checked_sub(*other))
     }
 
-    /// Subtracts a decimal value, returning None if the result would be negative.
+    /// Subtracts a decimal value, returning None if the result would be negative
+    /// or overflows.
     #[must_use]
     pub fn sub_or_none(&self, other: &Decimal) -> Option<Positive> {
-        if &self.0 >= other {
-            Some(Positive(self.0 - other))
-        } else {
-            None
-        }
+        self.0
+            .checked_sub(*other)
+            .and_then(|value| Positive::new_decimal(value).ok())
+    }
+
+    /// Checked addition that returns Result instead of panicking.
+    ///
+    /// # Errors
+    ///
+    /// Returns `PositiveError::ArithmeticError` if the sum overflows `Decimal`.
+    pub fn checked_add(&self, rhs: &Self) -> PositiveResult<Self> {
+        positive_result("addition", self.0.checked_add(rhs.0))
     }
 
     /// Checked subtraction that returns Result instead of panicking.
-    pub fn checked_sub(&self, rhs: &Self) -> Result<Self, PositiveError> {
-        Positive::new_decimal(self.0 - rhs.0)
+    ///
+    /// # Errors
+    ///
+    /// Returns `PositiveError::ArithmeticError` if the result would be negative
+    /// (or zero when the `non-zero` feature is enabled).
+    pub fn checked_sub(&self, rhs: &Self) -> PositiveResult<Self> {
+        positive_result("subtraction", self.0.checked_sub(rhs.0))
     }
 
-    /// Saturating subtraction that returns ZERO instead of negative.
+    /// Checked multiplication that returns Result instead of panicking.
     ///
-    /// This method is not available when the `non-zero` feature is enabled
-    /// because the result could be zero.
-    #[cfg(not(feature = "non-zero"))]
+    /// # Errors
+    ///
+    /// Returns `PositiveError::ArithmeticError` if the product overflows
+    /// `Decimal`, or underflows to zero when the `non-zero` feature is enabled.
+    pub fn checked_mul(&self, rhs: &Self) -> PositiveResult<Self> {
+        positive_result("multiplication", self.0.checked_mul(rhs.0))
+    }
+
+    /// Saturating addition that returns [`Positive::MAX`] instead of overflowing.
+    #[must_use]
+    pub fn saturating_add(&self, rhs: &Self) -> Self {
+        saturate(self.0.checked_add(rhs.0))
+    }
+
+    /// Saturating subtraction that returns [`Positive::MIN`] instead of negative.
+    ///
+    /// `Positive::MIN` is zero by default, and the smallest representable
+    /// positive value when the `non-zero` feature is enabled.
     #[must_use]
     pub fn saturating_sub(&self, rhs: &Self) -> Self {
-        if self.0 > rhs.0 {
-            Positive(self.0 - rhs.0)
+        saturate(self.0.checked_sub(rhs.0))
+    }
+
+    /// Saturating multiplication.
+    ///
+    /// Returns [`Positive::MAX`] on overflow, and [`Positive::MIN`] if the
+    /// product underflows to zero when the `non-zero` feature is enabled.
+    #[must_use]
+    pub fn saturating_mul(&self, rhs: &Self) -> Self {
+        saturate(self.0.checked_mul(rhs.0))
+    }
+
+    /// Saturating power with a `Positive` exponent.
+    ///
+    /// Returns [`Positive::MAX`] on overflow, and [`Positive::MIN`] if the
+    /// result underflows.
+    #[must_use]
+    pub fn saturating_pow(&self, n: Positive) -> Self {
+        saturate_pow(self.0.checked_powd(n.0), self.0 < Decimal::ONE)
+    }
+
+    /// Saturating integer power.
+    ///
+    /// Returns [`Positive::MAX`] on overflow (including zero raised to a
+    /// negative power), and [`Positive::MIN`] if the result underflows.
+    #[must_use]
+    pub fn saturating_powi(&self, n: i64) -> Self {
+        saturate_pow(self.0.checked_powi(n), (self.0 < Decimal::ONE) != (n < 0))
+    }
+
+    /// Saturating unsigned integer power.
+    ///
+    /// Returns [`Positive::MAX`] on overflow, and [`Positive::MIN`] if the
+    /// result underflows.
+    #[must_use]
+    pub fn saturating_powu(&self, n: u64) -> Self {
+        saturate_pow(self.0.checked_powu(n), self.0 < Decimal::ONE)
+    }
+
+    /// Checked division that returns Result instead of panicking.
+    ///
+    /// # Errors
+    ///
+    /// Returns `PositiveError::ArithmeticError` on division by zero or if the
+    /// quotient overflows `Decimal`.
+    pub fn checked_div(&self, rhs: &Self) -> PositiveResult<Self> {
+        if rhs.is_zero() {
+            Err(division_by_zero("division"))
         } else {
-            Positive::ZERO
+            positive_result("division", self.0.checked_div(rhs.0))
         }
     }
 
-    /// Checked division that returns Result instead of panicking.
-    pub fn checked_div(&self, rhs: &Self) -> Result<Self, PositiveError> {
+    /// Checked remainder that returns Result instead of panicking.
+    ///
+    /// # Errors
+    ///
+    /// Returns `PositiveError::ArithmeticError` if `rhs` is zero, or if the
+    /// remainder is zero when the `non-zero` feature is enabled.
+    pub fn checked_rem(&self, rhs: &Self) -> PositiveResult<Self> {
         if rhs.is_zero() {
-            Err(PositiveError::arithmetic_error(
-                "division",
-                "division by zero",
-            ))
+            Err(division_by_zero("remainder"))
         } else {
-            Ok(Positive(self.0 / rhs.0))
+            positive_result("remainder", self.0.checked_rem(rhs.0))
         }
     }
 
+    /// Splits `self` into a whole quotient and a remainder with respect to `rhs`.
+    fn euclid_parts(&self, rhs: &Self, operation: &str) -> PositiveResult<(Decimal, Decimal)> {
+        if rhs.is_zero() {
+            return Err(division_by_zero(operation));
+        }
+        let remainder = self.0 % rhs.0;
+        // `self - remainder` is an exact multiple of `rhs`, so the quotient is
+        // whole; truncating guards against rounding in the last digit.
+        let quotient = (self.0 - remainder)
+            .checked_div(rhs.0)
+            .map(|quotient| quotient.trunc())
+            .ok_or_else(|| overflow_error(operation))?;
+        Ok((quotient, remainder))
+    }
+
+    /// Euclidean division: the number of whole times `rhs` fits into `self`.
+    ///
+    /// For non-negative operands this is `floor(self / rhs)`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `PositiveError::ArithmeticError` if `rhs` is zero, or if the
+    /// quotient is zero when the `non-zero` feature is enabled.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use positive::pos_or_panic;
+    ///
+    /// let quantity = pos_or_panic!(1250.0);
+    /// let lot = pos_or_panic!(100.0);
+    /// assert_eq!(quantity.div_euclid(&lot).unwrap(), pos_or_panic!(12.0));
+    /// ```
+    pub fn div_euclid(&self, rhs: &Self) -> PositiveResult<Self> {
+        let (quotient, _) = self.euclid_parts(rhs, "div_euclid")?;
+        positive_result("div_euclid", Some(quotient))
+    }
+
+    /// Euclidean remainder: what is left of `self` after removing every whole
+    /// multiple of `rhs`.
+    ///
+    /// For non-negative operands this equals `self % rhs`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `PositiveError::ArithmeticError` if `rhs` is zero, or if the
+    /// remainder is zero when the `non-zero` feature is enabled.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use positive::pos_or_panic;
+    ///
+    /// let quantity = pos_or_panic!(1250.0);
+    /// let lot = pos_or_panic!(100.0);
+    /// assert_eq!(quantity.rem_euclid(&lot).unwrap(), pos_or_panic!(50.0));
+    /// ```
+    pub fn rem_euclid(&self, rhs: &Self) -> PositiveResult<Self> {
+        let (_, remainder) = self.euclid_parts(rhs, "rem_euclid")?;
+        positive_result("rem_euclid", Some(remainder))
+    }
+
+    /// Euclidean quotient and remainder in a single call.
+    ///
+    /// Equivalent to `(self.div_euclid(rhs)?, self.rem_euclid(rhs)?)`, and
+    /// `quotient * rhs + remainder == self` always holds.
+    ///
+    /// # Errors
+    ///
+    /// Returns `PositiveError::ArithmeticError` if `rhs` is zero, or if either
+    /// part is zero when the `non-zero` feature is enabled.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use positive::pos_or_panic;
+    ///
+    /// let (lots, leftover) = pos_or_panic!(1250.0)
+    ///     .div_rem(&pos_or_panic!(100.0))
+    ///     .unwrap();
+    /// assert_eq!(lots, pos_or_panic!(12.0));
+    /// assert_eq!(leftover, pos_or_panic!(50.0));
+    /// ```
+    pub fn div_rem(&self, rhs: &Self) -> PositiveResult<(Self, Self)> {
+        let (quotient, remainder) = self.euclid_parts(rhs, "div_rem")?;
+        Ok((
+            positive_result("div_rem", Some(quotient))?,
+            positive_result("div_rem", Some(remainder))?,
+        ))
+    }
+
+    /// Checked integer power that returns Result instead of panicking.
+    ///
+    /// # Errors
+    ///
+    /// Returns `PositiveError::ArithmeticError` if the result overflows
+    /// `Decimal`, with kind `DivisionByZero` for zero raised to a negative
+    /// power.
+    pub fn checked_powi(&self, n: i64) -> PositiveResult<Self> {
+        if self.is_zero() && n < 0 {
+            return Err(division_by_zero("powi"));
+        }
+        positive_result("powi", self.0.checked_powi(n))
+    }
+
+    /// Checked unsigned integer power that returns Result instead of panicking.
+    ///
+    /// # Errors
+    ///
+    /// Returns `PositiveError::ArithmeticError` if the result overflows `Decimal`.
+    pub fn checked_powu(&self, n: u64) -> PositiveResult<Self> {
+        positive_result("powu", self.0.checked_powu(n))
+    }
+
+    /// Checked power with a `Positive` exponent that returns Result instead of panicking.
+    ///
+    /// # Errors
+    ///
+    /// Returns `PositiveError::ArithmeticError` if the result overflows `Decimal`.
+    pub fn checked_pow(&self, n: Positive) -> PositiveResult<Self> {
+        positive_result("pow", self.0.checked_powd(n.0))
+    }
+
+    /// Checked decimal power that returns Result instead of panicking.
+    ///
+    /// # Errors
+    ///
+    /// Returns `PositiveError::ArithmeticError` if the result overflows `Decimal`
+    /// or cannot be computed, with kind `DivisionByZero` for zero raised to a
+    /// negative power.
+    pub fn checked_powd(&self, exponent: Decimal) -> PositiveResult<Self> {
+        if self.is_zero() && exponent.is_sign_negative() && !exponent.is_zero() {
+            return Err(division_by_zero("powd"));
+        }
+        positive_result("powd", self.0.checked_powd(exponent))
+    }
+
+    /// Checked exponential function that returns Result instead of panicking.
+    ///
+    /// # Errors
+    ///
+    /// Returns `PositiveError::ArithmeticError` if e^x overflows `Decimal`.
+    pub fn checked_exp(&self) -> PositiveResult<Self> {
+        positive_result("exp", self.0.checked_exp())
+    }
+
+    /// Checked natural logarithm that returns Result instead of panicking.
+    ///
+    /// # Errors
+    ///
+    /// Returns `PositiveError::ArithmeticError` if the value is zero, or if it
+    /// is below one (the logarithm would be negative).
+    pub fn checked_ln(&self) -> PositiveResult<Self> {
+        log_result("ln", self.0, self.0.checked_ln())
+    }
+
+    /// Checked base-10 logarithm that returns Result instead of panicking.
+    ///
+    /// # Errors
+    ///
+    /// Returns `PositiveError::ArithmeticError` if the value is zero, or if it
+    /// is below one (the logarithm would be negative).
+    pub fn checked_log10(&self) -> PositiveResult<Self> {
+        log_result("log10", self.0, self.0.checked_log10())
+    }
+
     /// Checks whether the value is a multiple of another f64 value.
     #[must_use]
     pub fn is_multiple(&self, other: f64) -> bool {
@@ -568,12 +1091,6 @@ impl PartialEq<&Positive> for Positive {
     }
 }
 
-impl From<Positive> for u64 {
-    fn from(pos_u64: Positive) -> Self {
-        pos_u64.0.to_u64().unwrap_or(0)
-    }
-}
-
 impl From<&Positive> for f64 {
     fn from(value: &Positive) -> Self {
         value.0.to_f64().unwrap_or(0.0)
@@ -586,12 +1103,6 @@ impl From<Positive> for f64 {
     }
 }
 
-impl From<Positive> for usize {
-    fn from(value: Positive) -> Self {
-        value.0.to_f64().unwrap_or(0.0) as usize
-    }
-}
-
 impl PartialEq<&Positive> for f64 {
     fn eq(&self, other: &&Positive) -> bool {
         self == &other.0.to_f64().unwrap_or(0.0)
@@ -644,17 +1155,6 @@ impl Add<Positive> for f64 {
     }
 }
 
-impl FromStr for Positive {
-    type Err = String;
-    fn from_str(s: &str) -> Result<Self, Self::Err> {
-        match s.parse::<Decimal>() {
-            Ok(value) if is_valid_positive_value(value) => Ok(Positive(value)),
-            Ok(value) => Err(format!("Value must be positive, got {value}")),
-            Err(e) => Err(format!("Failed to parse as Decimal: {e}")),
-        }
-    }
-}
-
 impl TryFrom<f64> for Positive {
     type Error = PositiveError;
 
@@ -668,19 +1168,6 @@ impl TryFrom<f64> for Positive {
     }
 }
 
-impl TryFrom<usize> for Positive {
-    type Error = PositiveError;
-
-    /// Attempts to convert a usize to a Positive value.
-    ///
-    /// # Errors
-    ///
-    /// Returns `PositiveError` if the value cannot be converted to Decimal.
-    fn try_from(value: usize) -> Result<Self, Self::Error> {
-        Positive::new(value as f64)
-    }
-}
-
 impl TryFrom<Decimal> for Positive {
     type Error = PositiveError;
 
@@ -707,70 +1194,9 @@ impl TryFrom<&Decimal> for Positive {
     }
 }
 
-impl TryFrom<i64> for Positive {
-    type Error = PositiveError;
-
-    /// Attempts to convert an i64 to a Positive value.
-    ///
-    /// # Errors
-    ///
-    /// Returns `PositiveError` if the value is negative.
-    fn try_from(value: i64) -> Result<Self, Self::Error> {
-        Positive::new_decimal(Decimal::from(value))
-    }
-}
-
-impl TryFrom<u64> for Positive {
-    type Error = PositiveError;
-
-    /// Attempts to convert a u64 to a Positive value.
-    ///
-    /// # Errors
-    ///
-    /// This conversion is infallible for u64 since all values are non-negative.
-    fn try_from(value: u64) -> Result<Self, Self::Error> {
-        Positive::new_decimal(Decimal::from(value))
-    }
-}
-
 impl From<&Positive> for Positive {
     fn from(value: &Positive) -> Self {
-        Positive(value.0)
-    }
-}
-
-impl Mul<f64> for Positive {
-    type Output = Positive;
-    fn mul(self, rhs: f64) -> Positive {
-        Positive::new(self.to_f64() * rhs).expect("Multiplication result must be positive")
-    }
-}
-
-impl Div<f64> for Positive {
-    type Output = Positive;
-    fn div(self, rhs: f64) -> Positive {
-        Positive::new(self.to_f64() / rhs).expect("Division result must be positive")
-    }
-}
-
-impl Div<f64> for &Positive {
-    type Output = Positive;
-    fn div(self, rhs: f64) -> Positive {
-        Positive::new(self.to_f64() / rhs).expect("Division result must be positive")
-    }
-}
-
-impl Sub<f64> for Positive {
-    type Output = Positive;
-    fn sub(self, rhs: f64) -> Self::Output {
-        Positive::new(self.to_f64() - rhs).expect("Subtraction result must be positive")
-    }
-}
-
-impl Add<f64> for Positive {
-    type Output = Positive;
-    fn add(self, rhs: f64) -> Self::Output {
-        Positive::new(self.to_f64() + rhs).expect("Addition result must be positive")
+        *value
     }
 }
 
@@ -798,40 +1224,6 @@ impl PartialEq<f64> for Positive {
     }
 }
 
-impl Display for Positive {
-    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        if *self == Positive::INFINITY {
-            write!(f, "{}", f64::MAX)
-        } else if self.0.scale() == 0 {
-            match self.0.to_i64() {
-                Some(val) => write!(f, "{val}"),
-                None => write!(f, "{}", self.0),
-            }
-        } else if let Some(precision) = f.precision() {
-            write!(f, "{:.1$}", self.0, precision)
-        } else {
-            let s = self.0.to_string();
-            let trimmed = s.trim_end_matches('0').trim_end_matches('.');
-            write!(f, "{trimmed}")
-        }
-    }
-}
-
-impl fmt::Debug for Positive {
-    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        if *self == Positive::INFINITY {
-            write!(f, "{}", f64::MAX)
-        } else if self.0.scale() == 0 {
-            match self.0.to_i64() {
-                Some(val) => write!(f, "{val}"),
-                None => write!(f, "{}", self.0),
-            }
-        } else {
-            write!(f, "{}", self.0)
-        }
-    }
-}
-
 impl PartialEq<Decimal> for Positive {
     fn eq(&self, other: &Decimal) -> bool {
         (self.0 - *other).abs() <= EPSILON * Decimal::from(100)
@@ -843,15 +1235,8 @@ impl Serialize for Positive {
     where
         S: Serializer,
     {
-        if *self == Positive::INFINITY {
-            return serializer.serialize_f64(f64::MAX);
-        }
-        if self.0.scale() == 0 {
-            serializer.serialize_i64(
-                self.0
-                    .to_i64()
-                    .ok_or_else(|| serde::ser::Error::custom("Failed to convert to i64"))?,
-            )
+        if let Some(integer) = self.0.to_i64().filter(|_| self.0.scale() == 0) {
+            serializer.serialize_i64(integer)
         } else {
             serializer.serialize_f64(
                 self.0
@@ -862,6 +1247,9 @@ impl Serialize for Positive {
     }
 }
 
+/// Map key `serde_json` uses for numbers when `arbitrary_precision` is enabled.
+const SERDE_JSON_NUMBER_TOKEN: &str = "$serde_json::private::Number";
+
 impl<'de> Deserialize<'de> for Positive {
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
     where
@@ -869,62 +1257,96 @@ impl<'de> Deserialize<'de> for Positive {
     {
         struct PositiveVisitor;
 
-        impl Visitor<'_> for PositiveVisitor {
+        impl<'de> Visitor<'de> for PositiveVisitor {
             type Value = Positive;
 
             fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
-                formatter.write_str("a positive number")
+                formatter.write_str("a positive number or numeric string")
             }
 
+            // Borrowed and owned strings forward here through the default
+            // `visit_borrowed_str` and `visit_string` impls.
             fn visit_str<E>(self, value: &str) -> Result<Self::Value, E>
             where
                 E: serde::de::Error,
             {
-                Err(serde::de::Error::custom(format!(
-                    "Invalid string: '{value}'. Expected a positive number."
-                )))
+                Positive::from_str(value).map_err(serde::de::Error::custom)
             }
 
             fn visit_i64<E>(self, value: i64) -> Result<Self::Value, E>
             where
                 E: serde::de::Error,
             {
-                let decimal = Decimal::from(value);
-                if !is_valid_positive_value(decimal) {
-                    Err(serde::de::Error::custom("Expected a positive integer"))
-                } else {
-                    Positive::new_decimal(decimal).map_err(serde::de::Error::custom)
-                }
+                Positive::new_decimal(Decimal::from(value)).map_err(serde::de::Error::custom)
             }
 
             fn visit_u64<E>(self, value: u64) -> Result<Self::Value, E>
             where
                 E: serde::de::Error,
             {
-                let decimal = Decimal::from(value);
-                if !is_valid_positive_value(decimal) {
-                    Err(serde::de::Error::custom("Expected a positive integer"))
-                } else {
-                    Positive::new_decimal(decimal).map_err(serde::de::Error::custom)
-                }
+                Positive::new_decimal(Decimal::from(value)).map_err(serde::de::Error::custom)
             }
 
             fn visit_f64<E>(self, value: f64) -> Result<Self::Value, E>
             where
                 E: serde::de::Error,
             {
-                if value.is_infinite() && value.is_sign_positive() {
-                    return Ok(Positive::INFINITY);
+                if value.is_infinite() {
+                    return Err(serde::de::Error::custom(
+                        "Positive cannot represent infinity; use PositiveExt for unbounded values",
+                    ));
                 }
-                if value == f64::MAX {
-                    return Ok(Positive::INFINITY);
+                // The same conversion as `Positive::new`, so JSON integers
+                // beyond `u64` keep their shortest round-trip digits.
+                Positive::new(value).map_err(serde::de::Error::custom)
+            }
+
+            fn visit_f32<E>(self, value: f32) -> Result<Self::Value, E>
+            where
+                E: serde::de::Error,
+            {
+                // Widening to f64 first would turn 0.1f32 into 0.100000001490116...
+                if value.is_infinite() {
+                    return self.visit_f64(f64::from(value));
                 }
-                let decimal = Decimal::from_f64(value)
-                    .ok_or_else(|| serde::de::Error::custom("Failed to convert f64 to Decimal"))?;
-                if !is_valid_positive_value(decimal) {
-                    Err(serde::de::Error::custom("Expected a positive float"))
-                } else {
-                    Positive::new_decimal(decimal).map_err(serde::de::Error::custom)
+                let decimal = Decimal::from_f32(value)
+                    .ok_or_else(|| serde::de::Error::custom("Failed to convert f32 to Decimal"))?;
+                Positive::new_decimal(decimal).map_err(serde::de::Error::custom)
+            }
+
+            fn visit_i128<E>(self, value: i128) -> Result<Self::Value, E>
+            where
+                E: serde::de::Error,
+            {
+                let decimal = Decimal::from_i128(value).ok_or_else(|| {
+                    serde::de::Error::custom(format!("{value} is out of range for Decimal"))
+                })?;
+                Positive::new_decimal(decimal).map_err(serde::de::Error::custom)
+            }
+
+            fn visit_u128<E>(self, value: u128) -> Result<Self::Value, E>
+            where
+                E: serde::de::Error,
+            {
+                let decimal = Decimal::from_u128(value).ok_or_else(|| {
+                    serde::de::Error::custom(format!("{value} is out of range for Decimal"))
+                })?;
+                Positive::new_decimal(decimal).map_err(serde::de::Error::custom)
+            }
+
+            // `serde_json` with the `arbitrary_precision` feature hands numbers
+            // over as a single-entry map holding the original text.
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                match map.next_key::<String>()?.as_deref() {
+                    Some(SERDE_JSON_NUMBER_TOKEN) => {
+                        let number: String = map.next_value()?;
+                        // The verbatim number text never goes through `f64`.
+                        Positive::from_str(&number).map_err(serde::de::Error::custom)
+                    }
+                    _ => Err(serde::de::Error::invalid_type(Unexpected::Map, &self)),
                 }
             }
         }
@@ -933,98 +1355,129 @@ impl<'de> Deserialize<'de> for Positive {
     }
 }
 
-impl Add for Positive {
-    type Output = Positive;
-    fn add(self, other: Positive) -> Positive {
-        Positive(self.0 + other.0)
-    }
-}
-
-impl Sub for Positive {
-    type Output = Positive;
-    fn sub(self, rhs: Self) -> Self::Output {
-        let result = self.0 - rhs.0;
-        if result < Decimal::ZERO {
-            panic!("Resulting value must be positive");
-        } else {
-            Positive(result)
+/// Implements a binary arithmetic operator for `Positive` and `&Positive`
+/// left-hand sides with `Positive`, `&Positive`, `Decimal`, `&Decimal` and
+/// `f64` right-hand sides.
+///
+/// Every generated impl returns a `Positive` validated with the same policy:
+/// an invalid or unrepresentable outcome panics with the `PositiveError`
+/// message, and the `Decimal` method `$checked` performs the actual
+/// computation. `f64` operands are converted to `Decimal` first.
+macro_rules! impl_binary_ops {
+    ($trait:ident, $method:ident, $checked:ident, $operation:literal) => {
+        impl_binary_ops!(@lhs Positive; $trait, $method, $checked, $operation);
+        impl_binary_ops!(@lhs &Positive; $trait, $method, $checked, $operation);
+    };
+    (@lhs $lhs:ty; $trait:ident, $method:ident, $checked:ident, $operation:literal) => {
+        impl $trait<Positive> for $lhs {
+            type Output = Positive;
+            #[track_caller]
+            fn $method(self, rhs: Positive) -> Positive {
+                expect_positive($operation, self.0.$checked(rhs.0))
+            }
         }
-    }
-}
 
-impl Div for Positive {
-    type Output = Positive;
-    fn div(self, other: Positive) -> Self::Output {
-        Positive(self.0 / other.0)
-    }
-}
+        impl $trait<&Positive> for $lhs {
+            type Output = Positive;
+            #[track_caller]
+            fn $method(self, rhs: &Positive) -> Positive {
+                expect_positive($operation, self.0.$checked(rhs.0))
+            }
+        }
 
-impl Div for &Positive {
-    type Output = Positive;
-    fn div(self, other: &Positive) -> Self::Output {
-        Positive(self.0 / other.0)
-    }
-}
+        impl $trait<Decimal> for $lhs {
+            type Output = Positive;
+            #[track_caller]
+            fn $method(self, rhs: Decimal) -> Positive {
+                expect_positive($operation, self.0.$checked(rhs))
+            }
+        }
 
-impl Add<Decimal> for Positive {
-    type Output = Positive;
-    fn add(self, rhs: Decimal) -> Positive {
-        Positive(self.0 + rhs)
-    }
-}
+        impl $trait<&Decimal> for $lhs {
+            type Output = Positive;
+            #[track_caller]
+            fn $method(self, rhs: &Decimal) -> Positive {
+                expect_positive($operation, self.0.$checked(*rhs))
+            }
+        }
 
-impl Add<&Decimal> for Positive {
-    type Output = Positive;
-    fn add(self, rhs: &Decimal) -> Self::Output {
-        Positive::new_decimal(self.0 + rhs).expect("Addition result must be positive")
-    }
+        impl $trait<f64> for $lhs {
+            type Output = Positive;
+            #[track_caller]
+            fn $method(self, rhs: f64) -> Positive {
+                expect_positive(
+                    $operation,
+                    FromF64Mode::default()
+                        .to_decimal(rhs)
+                        .and_then(|rhs| self.0.$checked(rhs)),
+                )
+            }
+        }
+    };
 }
 
-impl Sub<Decimal> for Positive {
-    type Output = Positive;
-    fn sub(self, rhs: Decimal) -> Positive {
-        Positive::new_decimal(self.0 - rhs).expect("Resulting value must be positive")
-    }
-}
+impl_binary_ops!(Add, add, checked_add, "addition");
+impl_binary_ops!(Sub, sub, checked_sub, "subtraction");
+impl_binary_ops!(Mul, mul, checked_mul, "multiplication");
+impl_binary_ops!(Div, div, checked_div, "division");
+impl_binary_ops!(Rem, rem, checked_rem, "remainder");
 
-impl Sub<&Decimal> for Positive {
-    type Output = Positive;
-    fn sub(self, rhs: &Decimal) -> Self::Output {
-        Positive::new_decimal(self.0 - rhs).expect("Resulting value must be positive")
-    }
-}
+/// Implements a compound-assignment operator for `Positive` with `Positive`,
+/// `&Positive`, `Decimal`, `&Decimal` and `f64` right-hand sides.
+///
+/// The result is validated exactly like the binary operators: an invalid or
+/// unrepresentable outcome panics with the `PositiveError` message and the
+/// `Decimal` method `$checked` performs the actual computation. `f64`
+/// operands are converted to `Decimal` first.
+macro_rules! impl_assign_ops {
+    ($trait:ident, $method:ident, $checked:ident, $operation:literal) => {
+        impl $trait for Positive {
+            #[track_caller]
+            fn $method(&mut self, rhs: Positive) {
+                *self = expect_positive($operation, self.0.$checked(rhs.0));
+            }
+        }
 
-impl AddAssign for Positive {
-    fn add_assign(&mut self, other: Positive) {
-        self.0 += other.0;
-    }
-}
+        impl $trait<&Positive> for Positive {
+            #[track_caller]
+            fn $method(&mut self, rhs: &Positive) {
+                *self = expect_positive($operation, self.0.$checked(rhs.0));
+            }
+        }
 
-impl AddAssign<Decimal> for Positive {
-    fn add_assign(&mut self, rhs: Decimal) {
-        self.0 += rhs;
-    }
-}
+        impl $trait<Decimal> for Positive {
+            #[track_caller]
+            fn $method(&mut self, rhs: Decimal) {
+                *self = expect_positive($operation, self.0.$checked(rhs));
+            }
+        }
 
-impl MulAssign<Decimal> for Positive {
-    fn mul_assign(&mut self, rhs: Decimal) {
-        self.0 *= rhs;
-    }
-}
+        impl $trait<&Decimal> for Positive {
+            #[track_caller]
+            fn $method(&mut self, rhs: &Decimal) {
+                *self = expect_positive($operation, self.0.$checked(*rhs));
+            }
+        }
 
-impl Div<Decimal> for Positive {
-    type Output = Positive;
-    fn div(self, rhs: Decimal) -> Positive {
-        Positive(self.0 / rhs)
-    }
+        impl $trait<f64> for Positive {
+            #[track_caller]
+            fn $method(&mut self, rhs: f64) {
+                *self = expect_positive(
+                    $operation,
+                    FromF64Mode::default()
+                        .to_decimal(rhs)
+                        .and_then(|rhs| self.0.$checked(rhs)),
+                );
+            }
+        }
+    };
 }
 
-impl Div<&Decimal> for Positive {
-    type Output = Positive;
-    fn div(self, rhs: &Decimal) -> Self::Output {
-        Positive::new_decimal(self.0 / rhs).expect("Division result must be positive")
-    }
-}
+impl_assign_ops!(AddAssign, add_assign, checked_add, "addition");
+impl_assign_ops!(SubAssign, sub_assign, checked_sub, "subtraction");
+impl_assign_ops!(MulAssign, mul_assign, checked_mul, "multiplication");
+impl_assign_ops!(DivAssign, div_assign, checked_div, "division");
+impl_assign_ops!(RemAssign, rem_assign, checked_rem, "remainder");
 
 impl PartialOrd<Decimal> for Positive {
     fn partial_cmp(&self, other: &Decimal) -> Option<Ordering> {
@@ -1061,20 +1514,6 @@ impl Neg for Positive {
     }
 }
 
-impl Mul for Positive {
-    type Output = Positive;
-    fn mul(self, other: Positive) -> Positive {
-        Positive(self.0 * other.0)
-    }
-}
-
-impl Mul<Decimal> for Positive {
-    type Output = Positive;
-    fn mul(self, rhs: Decimal) -> Positive {
-        Positive(self.0 * rhs)
-    }
-}
-
 impl Mul<Positive> for Decimal {
     type Output = Decimal;
     fn mul(self, rhs: Positive) -> Decimal {
@@ -1195,19 +1634,3 @@ impl RelativeEq for Positive {
         abs_diff <= epsilon || abs_diff <= max_relative * largest
     }
 }
-
-#[cfg(not(feature = "non-zero"))]
-impl Sum for Positive {
-    fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
-        let sum = iter.fold(Decimal::ZERO, |acc, x| acc + x.value());
-        Positive::new_decimal(sum).unwrap_or(Positive::ZERO)
-    }
-}
-
-#[cfg(not(feature = "non-zero"))]
-impl<'a> Sum<&'a Positive> for Positive {
-    fn sum<I: Iterator<Item = &'a Positive>>(iter: I) -> Self {
-        let sum = iter.fold(Decimal::ZERO, |acc, x| acc + x.value());
-        Positive::new_decimal(sum).unwrap_or(Positive::ZERO)
-    }
-}
@@ -16,10 +16,15 @@
 //! This includes:
 //! - The `Positive` type and its associated macros
 //! - Error types for handling failures
+//! - The `PositiveAccumulator` type and `PositiveIteratorExt` iterator extensions
 //! - The `Decimal` type from `rust_decimal`
 //! - All predefined constants
 
 pub use crate::constants::*;
-pub use crate::error::{PositiveError, PositiveResult};
-pub use crate::{Positive, is_positive, pos, pos_or_panic, spos};
-pub use rust_decimal::Decimal;
+pub use crate::error::{PositiveError, PositiveErrorKind, PositiveResult};
+pub use crate::{
+    FromF64Mode, Increment, ParseOptions, Positive, PositiveAccumulator, PositiveExt,
+    PositiveFormatter, PositiveIteratorExt, PositiveParser, SuffixTable, TickSize, is_positive,
+    pos, pos_or_panic, spos,
+};
+pub use rust_decimal::{Decimal, RoundingStrategy};
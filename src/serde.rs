@@ -0,0 +1,381 @@
+/******************************************************************************
+   Author: Joaquín Béjar García
+   Email: jb@taunais.com
+   Date: 18/10/26
+******************************************************************************/
+
+//! Alternative serde representations for `Positive`.
+//!
+//! The default `Serialize` impl writes integers as `i64` and everything else
+//! as `f64`, which is convenient but lossy. The items in here select another
+//! encoding per field through `#[serde(with = "...")]`:
+//!
+//! | Path                       | Encoding of `123.45`                       |
+//! |----------------------------|--------------------------------------------|
+//! | [`as_str`]                 | `"123.45"` (exact, scale preserved)        |
+//! | [`as_f64`]                 | `123.45` (always a float)                  |
+//! | [`as_minor_units::<2>`]    | `12345` (integer count of `10^-SCALE`)     |
+//! | [`as_parts`]               | `{"mantissa": 12345, "scale": 2}`          |
+//!
+//! Each representation also has `_option` and `_vec` variants (for example
+//! `as_str_option` and `as_minor_units_vec::<2>`) for `Option<Positive>` and
+//! `Vec<Positive>` fields.
+//!
+//! [`as_minor_units::<2>`]: as_minor_units
+//!
+//! # Example
+//!
+//! ```rust
+//! use positive::Positive;
+//! use serde::{Deserialize, Serialize};
+//!
+//! #[derive(Serialize, Deserialize)]
+//! struct Order {
+//!     #[serde(with = "positive::serde::as_str")]
+//!     price: Positive,
+//!     #[serde(with = "positive::serde::as_minor_units::<2>")]
+//!     fee: Positive,
+//!     #[serde(with = "positive::serde::as_f64_option")]
+//!     limit: Option<Positive>,
+//! }
+//!
+//! let json = r#"{"price":"101.250","fee":125,"limit":null}"#;
+//! let order: Order = serde_json::from_str(json).unwrap();
+//! assert_eq!(order.fee, positive::pos_or_panic!(1.25));
+//! assert_eq!(serde_json::to_string(&order).unwrap(), json);
+//! ```
+
+#![allow(non_camel_case_types)]
+
+use crate::Positive;
+use ::serde::de::{self, Deserialize, Deserializer};
+use ::serde::ser::{Serialize, Serializer};
+use rust_decimal::Decimal;
+
+/// Generates the `_option` and `_vec` modules for a representation module.
+///
+/// `$repr` is a private newtype whose `Serialize`/`Deserialize` impls
+/// delegate to the base module.
+macro_rules! impl_collection_modules {
+    ($base:ident, $repr:ident, $option:ident, $vec:ident) => {
+        struct $repr(Positive);
+
+        impl Serialize for $repr {
+            fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                $base::serialize(&self.0, serializer)
+            }
+        }
+
+        impl<'de> Deserialize<'de> for $repr {
+            fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+                $base::deserialize(deserializer).map($repr)
+            }
+        }
+
+        #[doc = concat!("[`", stringify!($base), "`] for `Option<Positive>` fields.")]
+        pub mod $option {
+            use super::$repr;
+            use crate::Positive;
+            use ::serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+            #[doc = concat!("Serializes `Some` like [`", stringify!($base), "`](super::", stringify!($base), ") and `None` as the format's null.")]
+            pub fn serialize<S>(value: &Option<Positive>, serializer: S) -> Result<S::Ok, S::Error>
+            where
+                S: Serializer,
+            {
+                value.map($repr).serialize(serializer)
+            }
+
+            /// Deserializes an optional `Positive`.
+            pub fn deserialize<'de, D>(deserializer: D) -> Result<Option<Positive>, D::Error>
+            where
+                D: Deserializer<'de>,
+            {
+                Option::<$repr>::deserialize(deserializer).map(|value| value.map(|value| value.0))
+            }
+        }
+
+        #[doc = concat!("[`", stringify!($base), "`] for `Vec<Positive>` fields.")]
+        pub mod $vec {
+            use super::$repr;
+            use crate::Positive;
+            use ::serde::{Deserialize, Deserializer, Serializer};
+
+            #[doc = concat!("Serializes every element like [`", stringify!($base), "`](super::", stringify!($base), ").")]
+            pub fn serialize<S>(values: &[Positive], serializer: S) -> Result<S::Ok, S::Error>
+            where
+                S: Serializer,
+            {
+                serializer.collect_seq(values.iter().copied().map($repr))
+            }
+
+            /// Deserializes a sequence of `Positive` values.
+            pub fn deserialize<'de, D>(deserializer: D) -> Result<Vec<Positive>, D::Error>
+            where
+                D: Deserializer<'de>,
+            {
+                Vec::<$repr>::deserialize(deserializer)
+                    .map(|values| values.into_iter().map(|value| value.0).collect())
+            }
+        }
+    };
+}
+
+/// Serializes a `Positive` as its exact decimal string.
+///
+/// The string keeps the scale of the underlying `Decimal`, so `123.4500`
+/// is written as `"123.4500"` and reads back bit-identically. Deserializing
+/// accepts strings only and rejects input that cannot be represented
+/// without rounding.
+///
+/// # Example
+///
+/// ```rust
+/// use positive::Positive;
+/// use rust_decimal_macros::dec;
+/// use serde::{Deserialize, Serialize};
+///
+/// #[derive(Serialize, Deserialize)]
+/// struct Fill {
+///     #[serde(with = "positive::serde::as_str")]
+///     price: Positive,
+/// }
+///
+/// let fill = Fill { price: Positive::new_decimal(dec!(123.4500)).unwrap() };
+/// let json = serde_json::to_string(&fill).unwrap();
+/// assert_eq!(json, r#"{"price":"123.4500"}"#);
+///
+/// let parsed: Fill = serde_json::from_str(&json).unwrap();
+/// assert_eq!(parsed.price.into_inner().scale(), 4);
+/// ```
+pub mod as_str {
+    use crate::Positive;
+    use ::serde::de::{self, Visitor};
+    use ::serde::{Deserializer, Serializer};
+    use rust_decimal::Decimal;
+    use std::fmt;
+
+    /// Serializes `value` as its exact decimal string.
+    pub fn serialize<S>(value: &Positive, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.collect_str(value.as_decimal())
+    }
+
+    /// Deserializes a `Positive` from an exact decimal string.
+    pub fn deserialize<'de, D>(deserializer: D) -> Result<Positive, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_str(ExactStrVisitor)
+    }
+
+    struct ExactStrVisitor;
+
+    impl Visitor<'_> for ExactStrVisitor {
+        type Value = Positive;
+
+        fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+            formatter.write_str("a positive decimal string")
+        }
+
+        fn visit_str<E>(self, value: &str) -> Result<Self::Value, E>
+        where
+            E: de::Error,
+        {
+            let decimal = Decimal::from_str_exact(value)
+                .map_err(|e| de::Error::custom(format!("Invalid decimal string '{value}': {e}")))?;
+            Positive::new_decimal(decimal).map_err(de::Error::custom)
+        }
+    }
+}
+
+impl_collection_modules!(as_str, StrRepr, as_str_option, as_str_vec);
+
+/// Serializes a `Positive` as an `f64`, even when it holds an integer.
+///
+/// Deserializing goes through the regular `Deserialize` impl, so integers
+/// and numeric strings are accepted as well.
+pub mod as_f64 {
+    use crate::Positive;
+    use ::serde::{Deserialize, Deserializer, Serializer};
+
+    /// Serializes `value` as the nearest `f64`.
+    pub fn serialize<S>(value: &Positive, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let value = value
+            .to_f64_checked()
+            .ok_or_else(|| ::serde::ser::Error::custom("Failed to convert to f64"))?;
+        serializer.serialize_f64(value)
+    }
+
+    /// Deserializes a `Positive` with the regular `Deserialize` impl.
+    pub fn deserialize<'de, D>(deserializer: D) -> Result<Positive, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        Positive::deserialize(deserializer)
+    }
+}
+
+impl_collection_modules!(as_f64, F64Repr, as_f64_option, as_f64_vec);
+
+/// Serializes a `Positive` as `{ "mantissa": .., "scale": .. }`.
+///
+/// The value is `mantissa * 10^-scale`, exactly as stored by `Decimal`, so
+/// the round trip is bit-identical.
+pub mod as_parts {
+    use crate::Positive;
+    use ::serde::de;
+    use ::serde::{Deserialize, Deserializer, Serialize, Serializer};
+    use rust_decimal::Decimal;
+
+    #[derive(Serialize, Deserialize)]
+    #[serde(deny_unknown_fields)]
+    struct Parts {
+        mantissa: u128,
+        scale: u32,
+    }
+
+    /// Serializes `value` as its `Decimal` mantissa and scale.
+    pub fn serialize<S>(value: &Positive, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let decimal = value.as_decimal();
+        Parts {
+            mantissa: decimal.mantissa().unsigned_abs(),
+            scale: decimal.scale(),
+        }
+        .serialize(serializer)
+    }
+
+    /// Deserializes a `Positive` from a mantissa and a scale.
+    pub fn deserialize<'de, D>(deserializer: D) -> Result<Positive, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let parts = Parts::deserialize(deserializer)?;
+        let decimal = i128::try_from(parts.mantissa)
+            .ok()
+            .and_then(|mantissa| Decimal::try_from_i128_with_scale(mantissa, parts.scale).ok())
+            .ok_or_else(|| {
+                de::Error::custom(format!(
+                    "mantissa {} with scale {} is not a valid Decimal",
+                    parts.mantissa, parts.scale
+                ))
+            })?;
+        Positive::new_decimal(decimal).map_err(de::Error::custom)
+    }
+}
+
+impl_collection_modules!(as_parts, PartsRepr, as_parts_option, as_parts_vec);
+
+/// Serializes a `Positive` as an integer number of minor units (`10^-SCALE`).
+///
+/// With `SCALE = 2`, `12.34` is written as `1234`. Serializing fails instead
+/// of rounding when the value has more than `SCALE` decimal places.
+///
+/// Use it as `#[serde(with = "positive::serde::as_minor_units::<2>")]`.
+pub struct as_minor_units<const SCALE: u32>;
+
+impl<const SCALE: u32> as_minor_units<SCALE> {
+    /// Serializes `value` as a `u64` count of minor units.
+    pub fn serialize<S>(value: &Positive, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_u64(to_minor_units(value, SCALE).map_err(::serde::ser::Error::custom)?)
+    }
+
+    /// Deserializes a `Positive` from a `u64` count of minor units.
+    pub fn deserialize<'de, D>(deserializer: D) -> Result<Positive, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let units = u64::deserialize(deserializer)?;
+        let decimal = Decimal::try_from_i128_with_scale(i128::from(units), SCALE)
+            .map_err(|e| de::Error::custom(format!("Invalid scale {SCALE}: {e}")))?;
+        Positive::new_decimal(decimal).map_err(de::Error::custom)
+    }
+}
+
+/// [`as_minor_units`] for `Option<Positive>` fields.
+pub struct as_minor_units_option<const SCALE: u32>;
+
+impl<const SCALE: u32> as_minor_units_option<SCALE> {
+    /// Serializes `Some` like [`as_minor_units`] and `None` as the format's null.
+    pub fn serialize<S>(value: &Option<Positive>, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        value.map(MinorUnitsRepr::<SCALE>).serialize(serializer)
+    }
+
+    /// Deserializes an optional `Positive`.
+    pub fn deserialize<'de, D>(deserializer: D) -> Result<Option<Positive>, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        Option::<MinorUnitsRepr<SCALE>>::deserialize(deserializer)
+            .map(|value| value.map(|value| value.0))
+    }
+}
+
+/// [`as_minor_units`] for `Vec<Positive>` fields.
+pub struct as_minor_units_vec<const SCALE: u32>;
+
+impl<const SCALE: u32> as_minor_units_vec<SCALE> {
+    /// Serializes every element like [`as_minor_units`].
+    pub fn serialize<S>(values: &[Positive], serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.collect_seq(values.iter().copied().map(MinorUnitsRepr::<SCALE>))
+    }
+
+    /// Deserializes a sequence of `Positive` values.
+    pub fn deserialize<'de, D>(deserializer: D) -> Result<Vec<Positive>, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        Vec::<MinorUnitsRepr<SCALE>>::deserialize(deserializer)
+            .map(|values| values.into_iter().map(|value| value.0).collect())
+    }
+}
+
+struct MinorUnitsRepr<const SCALE: u32>(Positive);
+
+impl<const SCALE: u32> Serialize for MinorUnitsRepr<SCALE> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        as_minor_units::<SCALE>::serialize(&self.0, serializer)
+    }
+}
+
+impl<'de, const SCALE: u32> Deserialize<'de> for MinorUnitsRepr<SCALE> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        as_minor_units::<SCALE>::deserialize(deserializer).map(MinorUnitsRepr)
+    }
+}
+
+/// Converts `value` into an exact count of `10^-scale` units.
+fn to_minor_units(value: &Positive, scale: u32) -> Result<u64, String> {
+    let decimal = value.as_decimal().normalize();
+    if decimal.scale() > scale {
+        return Err(format!(
+            "{decimal} has more than {scale} decimal places and cannot be written as minor units"
+        ));
+    }
+    let mut units = decimal;
+    units.rescale(scale);
+    if units.scale() != scale || units != decimal {
+        return Err(format!(
+            "{decimal} cannot be scaled to {scale} decimal places"
+        ));
+    }
+    u64::try_from(units.mantissa())
+        .map_err(|_| format!("{decimal} is too large to be written as u64 minor units"))
+}
@@ -0,0 +1,169 @@
+/******************************************************************************
+   Author: Joaquín Béjar García
+   Email: jb@taunais.com
+   Date: 18/10/26
+******************************************************************************/
+
+//! Integration tests for `PositiveAccumulator` and the iterator extensions.
+
+use positive::pos_or_panic;
+use positive::{Positive, PositiveAccumulator, PositiveError, PositiveIteratorExt, PositiveResult};
+use rust_decimal_macros::dec;
+
+#[test]
+fn test_accumulator_push_sum_and_count() {
+    let mut acc = PositiveAccumulator::new();
+    assert!(acc.is_empty());
+    acc.push(pos_or_panic!(1.5)).unwrap();
+    acc.push(pos_or_panic!(2.5)).unwrap();
+    assert_eq!(acc.count(), 2);
+    assert!(!acc.is_empty());
+    assert_eq!(acc.sum().unwrap(), dec!(4));
+}
+
+#[test]
+fn test_accumulator_mean() {
+    let acc = [pos_or_panic!(1.0), pos_or_panic!(2.0), pos_or_panic!(6.0)]
+        .iter()
+        .accumulate()
+        .unwrap();
+    assert_eq!(acc.mean().unwrap(), dec!(3));
+}
+
+#[test]
+fn test_accumulator_mean_of_empty_is_error() {
+    let acc = PositiveAccumulator::default();
+    assert!(matches!(
+        acc.mean(),
+        Err(PositiveError::ArithmeticError { .. })
+    ));
+}
+
+#[test]
+fn test_accumulator_overflow_leaves_state_unchanged() {
+    let mut acc = PositiveAccumulator::new();
+    acc.push(Positive::MAX).unwrap();
+    let err = acc.push(Positive::MAX).unwrap_err();
+    assert!(matches!(err, PositiveError::ArithmeticError { .. }));
+    assert_eq!(acc.count(), 1);
+    assert_eq!(acc.sum().unwrap(), Positive::MAX);
+}
+
+#[test]
+fn test_try_sum_owned_and_borrowed() {
+    let values = vec![pos_or_panic!(1.0), pos_or_panic!(2.0), pos_or_panic!(3.0)];
+    assert_eq!(values.iter().try_sum().unwrap(), dec!(6));
+    assert_eq!(values.into_iter().try_sum().unwrap(), dec!(6));
+}
+
+#[test]
+fn test_try_sum_overflow() {
+    let values = [Positive::MAX, Positive::ONE];
+    assert!(matches!(
+        values.iter().try_sum(),
+        Err(PositiveError::ArithmeticError { .. })
+    ));
+    assert_eq!(values.iter().checked_sum(), None);
+}
+
+#[test]
+fn test_try_mean() {
+    let values = [pos_or_panic!(2.0), pos_or_panic!(4.0)];
+    assert_eq!(values.iter().try_mean().unwrap(), dec!(3));
+    assert!(std::iter::empty::<Positive>().try_mean().is_err());
+}
+
+#[test]
+fn test_sum_into_result_and_option() {
+    let values = [pos_or_panic!(1.0), pos_or_panic!(2.0)];
+    let sum: PositiveResult<Positive> = values.iter().sum();
+    assert_eq!(sum.unwrap(), dec!(3));
+    let sum: Option<Positive> = values.into_iter().sum();
+    assert_eq!(sum, Some(Positive::THREE));
+
+    let overflow: Option<Positive> = [Positive::MAX, Positive::MAX].iter().sum();
+    assert_eq!(overflow, None);
+}
+
+#[cfg(not(feature = "non-zero"))]
+#[test]
+fn test_empty_sum_is_zero() {
+    assert_eq!(
+        std::iter::empty::<Positive>().try_sum().unwrap(),
+        Positive::ZERO
+    );
+    let sum: Option<Positive> = std::iter::empty::<Positive>().sum();
+    assert_eq!(sum, Some(Positive::ZERO));
+    assert_eq!(PositiveAccumulator::new().sum().unwrap(), Positive::ZERO);
+}
+
+#[cfg(feature = "non-zero")]
+#[test]
+fn test_empty_sum_is_error_non_zero() {
+    assert!(std::iter::empty::<Positive>().try_sum().is_err());
+    let sum: Option<Positive> = std::iter::empty::<Positive>().sum();
+    assert_eq!(sum, None);
+    let sum: PositiveResult<Positive> = std::iter::empty::<Positive>().sum();
+    assert!(sum.is_err());
+}
+
+#[cfg(not(feature = "non-zero"))]
+#[test]
+#[should_panic(expected = "Arithmetic error during sum")]
+fn test_sum_overflow_panics() {
+    let _: Positive = [Positive::MAX, Positive::MAX].into_iter().sum();
+}
+
+#[test]
+fn test_product_owned_and_borrowed() {
+    let factors = [pos_or_panic!(1.05), pos_or_panic!(1.10), Positive::TWO];
+    let product: Positive = factors.iter().product();
+    assert_eq!(product, dec!(2.31));
+    let product: Positive = factors.into_iter().product();
+    assert_eq!(product, dec!(2.31));
+}
+
+#[test]
+fn test_empty_product_is_one() {
+    let product: Positive = std::iter::empty::<Positive>().product();
+    assert_eq!(product, Positive::ONE);
+    assert_eq!(
+        std::iter::empty::<Positive>().try_product().unwrap(),
+        Positive::ONE
+    );
+}
+
+#[test]
+fn test_try_product() {
+    let factors = [Positive::TWO, Positive::THREE, Positive::FOUR];
+    assert_eq!(factors.iter().try_product().unwrap(), dec!(24));
+    assert_eq!(factors.iter().checked_product(), Some(pos_or_panic!(24.0)));
+}
+
+#[test]
+fn test_try_product_overflow() {
+    let factors = [Positive::MAX, Positive::TWO, pos_or_panic!(0.1)];
+    assert!(matches!(
+        factors.iter().try_product(),
+        Err(PositiveError::ArithmeticError { .. })
+    ));
+    assert_eq!(factors.iter().checked_product(), None);
+
+    let product: PositiveResult<Positive> = factors.iter().product();
+    assert!(product.is_err());
+    let product: Option<Positive> = factors.into_iter().product();
+    assert_eq!(product, None);
+}
+
+#[test]
+#[should_panic(expected = "Arithmetic error during product")]
+fn test_product_overflow_panics() {
+    let _: Positive = [Positive::MAX, Positive::MAX].into_iter().product();
+}
+
+#[cfg(feature = "non-zero")]
+#[test]
+fn test_try_product_underflow_non_zero() {
+    let tiny = Positive::MIN;
+    assert!([tiny, tiny].iter().try_product().is_err());
+}
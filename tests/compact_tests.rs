@@ -0,0 +1,161 @@
+/******************************************************************************
+   Author: Joaquín Béjar García
+   Email: jb@taunais.com
+   Date: 18/10/26
+******************************************************************************/
+
+use positive::{Positive, PositiveError, SuffixTable, pos_or_panic};
+use rust_decimal_macros::dec;
+
+// ============================================================================
+// Compact
+// ============================================================================
+
+#[test]
+fn test_format_compact() {
+    assert_eq!(pos_or_panic!(1234.0).format_compact(2), "1.2K");
+    assert_eq!(pos_or_panic!(3450000.0).format_compact(3), "3.45M");
+    assert_eq!(pos_or_panic!(12500000000.0).format_compact(3), "12.5B");
+    assert_eq!(pos_or_panic!(7000000000000.0).format_compact(3), "7T");
+    assert_eq!(pos_or_panic!(999.0).format_compact(3), "999");
+    assert_eq!(pos_or_panic!(0.0123).format_compact(2), "0.012");
+}
+
+#[test]
+fn test_format_compact_beyond_table() {
+    assert_eq!(pos_or_panic!(1234000000000000.0).format_compact(3), "1230T");
+    assert_eq!(Positive::MAX.format_compact(3), "79200000000000000T");
+}
+
+#[test]
+fn test_format_compact_rounding() {
+    // Half to even, like `round_to`.
+    assert_eq!(pos_or_panic!(1250.0).format_compact(2), "1.2K");
+    assert_eq!(pos_or_panic!(1350.0).format_compact(2), "1.4K");
+    assert_eq!(pos_or_panic!(1500.0).format_compact(0), "2K");
+    assert_eq!(pos_or_panic!(1234.5678).format_compact(28), "1.2345678K");
+}
+
+#[test]
+fn test_format_compact_carries_into_next_suffix() {
+    assert_eq!(pos_or_panic!(999960.0).format_compact(4), "1M");
+    assert_eq!(pos_or_panic!(999.6).format_compact(3), "1K");
+    assert_eq!(pos_or_panic!(999.4).format_compact(3), "999");
+}
+
+#[test]
+#[cfg(not(feature = "non-zero"))]
+fn test_format_zero() {
+    assert_eq!(Positive::ZERO.format_compact(3), "0");
+    assert_eq!(Positive::ZERO.format_si(3), "0");
+    assert_eq!(Positive::ZERO.format_engineering(3), "0e0");
+}
+
+// ============================================================================
+// SI and custom tables
+// ============================================================================
+
+#[test]
+fn test_format_si() {
+    assert_eq!(pos_or_panic!(0.00047).format_si(3), "470µ");
+    assert_eq!(pos_or_panic!(0.5).format_si(3), "500m");
+    assert_eq!(pos_or_panic!(2500.0).format_si(3), "2.5k");
+    assert_eq!(pos_or_panic!(4200000000.0).format_si(2), "4.2G");
+    assert_eq!(pos_or_panic!(1.0).format_si(3), "1");
+}
+
+#[test]
+fn test_format_si_below_smallest_prefix() {
+    // Below `p`, SI falls back to engineering notation.
+    assert_eq!(pos_or_panic!(0.0000000000000012).format_si(3), "1.2e-15");
+    assert_eq!(
+        pos_or_panic!(0.0000000000000012).format_si(3),
+        pos_or_panic!(0.0000000000000012).format_engineering(3)
+    );
+    assert_eq!(
+        pos_or_panic!(0.0000000000000000000000000001).format_si(2),
+        "100e-30"
+    );
+    assert_eq!(pos_or_panic!(0.0000000000012).format_si(3), "1.2p");
+    // Rounding up to the smallest prefix keeps it.
+    assert_eq!(pos_or_panic!(0.00000000000099996).format_si(3), "1p");
+}
+
+#[test]
+fn test_financial_table() {
+    let table = SuffixTable::financial();
+    assert_eq!(
+        pos_or_panic!(12500000000.0).format_with_suffixes(3, &table),
+        "12.5bn"
+    );
+    assert_eq!(
+        pos_or_panic!(3450000.0).format_with_suffixes(3, &table),
+        "3.45m"
+    );
+    assert_eq!(
+        pos_or_panic!(1200.0).format_with_suffixes(2, &table),
+        "1.2k"
+    );
+}
+
+#[test]
+fn test_custom_table() {
+    let table = SuffixTable::new([(6, "replaced"), (5, " lakh"), (7, " crore"), (6, "")]).unwrap();
+    assert_eq!(
+        pos_or_panic!(250000.0).format_with_suffixes(3, &table),
+        "2.5 lakh"
+    );
+    assert_eq!(
+        pos_or_panic!(2500000.0).format_with_suffixes(3, &table),
+        "2.5"
+    );
+    assert_eq!(
+        pos_or_panic!(25000000.0).format_with_suffixes(3, &table),
+        "2.5 crore"
+    );
+
+    let units = SuffixTable::new([(0, " u")]).unwrap();
+    assert_eq!(pos_or_panic!(42.0).format_with_suffixes(3, &units), "42 u");
+}
+
+#[test]
+fn test_custom_table_rejects_out_of_range_exponent() {
+    assert!(matches!(
+        SuffixTable::new([(29, "X")]),
+        Err(PositiveError::InvalidPrecision { precision: 29, .. })
+    ));
+    assert!(SuffixTable::new([(-28, "tiny"), (28, "huge")]).is_ok());
+}
+
+// ============================================================================
+// Engineering
+// ============================================================================
+
+#[test]
+fn test_format_engineering() {
+    assert_eq!(pos_or_panic!(12500000.0).format_engineering(3), "12.5e6");
+    assert_eq!(pos_or_panic!(1234.0).format_engineering(2), "1.2e3");
+    assert_eq!(pos_or_panic!(0.0125).format_engineering(3), "12.5e-3");
+    assert_eq!(pos_or_panic!(0.000001).format_engineering(3), "1e-6");
+    assert_eq!(pos_or_panic!(999999.0).format_engineering(3), "1e6");
+    assert_eq!(pos_or_panic!(7.0).format_engineering(3), "7e0");
+    assert_eq!(Positive::MAX.format_engineering(3), "79.2e27");
+    assert_eq!(
+        pos_or_panic!(0.0000000000000000000000000001).format_engineering(3),
+        "100e-30"
+    );
+}
+
+// ============================================================================
+// round_to_nice_number
+// ============================================================================
+
+#[test]
+fn test_round_to_nice_number_exact_powers_of_ten() {
+    assert_eq!(pos_or_panic!(1000.0).round_to_nice_number(), dec!(1000));
+    assert_eq!(pos_or_panic!(0.001).round_to_nice_number(), dec!(0.001));
+    assert_eq!(
+        pos_or_panic!(999.9999999999999999999).round_to_nice_number(),
+        dec!(1000)
+    );
+}
@@ -0,0 +1,280 @@
+/******************************************************************************
+   Author: Joaquín Béjar García
+   Email: jb@taunais.com
+   Date: 18/10/26
+******************************************************************************/
+
+// Unsigned conversions are `From` by default but `TryFrom` with `non-zero`.
+#![allow(clippy::unnecessary_fallible_conversions)]
+
+use positive::{Positive, PositiveErrorKind, pos_or_panic};
+use rust_decimal::Decimal;
+use rust_decimal::RoundingStrategy;
+use rust_decimal_macros::dec;
+use std::num::{
+    NonZeroI8, NonZeroI64, NonZeroI128, NonZeroU8, NonZeroU64, NonZeroU128, NonZeroUsize,
+};
+
+// ============================================================================
+// Integers into Positive
+// ============================================================================
+
+#[test]
+fn test_large_integers_are_exact() {
+    // 2^53 + 1 is the first integer an f64 cannot hold.
+    let value = (1_u64 << 53) + 1;
+    assert_eq!(
+        Positive::try_from(value as usize).unwrap().to_dec(),
+        Decimal::from(value)
+    );
+    assert_eq!(
+        Positive::try_from(u64::MAX).unwrap().to_dec(),
+        Decimal::from(u64::MAX)
+    );
+    assert_eq!(
+        Positive::try_from(i64::MAX).unwrap().to_dec(),
+        Decimal::from(i64::MAX)
+    );
+}
+
+#[test]
+#[cfg(not(feature = "non-zero"))]
+fn test_unsigned_from_is_infallible() {
+    assert_eq!(Positive::from(7_u8), Positive::from(7_u64));
+    assert_eq!(Positive::from(7_u16).to_dec(), dec!(7));
+    assert_eq!(Positive::from(7_u32).to_dec(), dec!(7));
+    assert_eq!(Positive::from(7_usize).to_dec(), dec!(7));
+    assert!(Positive::from(0_u32).is_zero());
+}
+
+#[test]
+#[cfg(feature = "non-zero")]
+fn test_unsigned_zero_is_rejected() {
+    assert_eq!(
+        Positive::try_from(0_u32).unwrap_err().kind(),
+        PositiveErrorKind::Zero
+    );
+    assert_eq!(Positive::try_from(7_u8).unwrap().to_dec(), dec!(7));
+}
+
+#[test]
+fn test_non_zero_unsigned_from() {
+    assert_eq!(Positive::from(NonZeroU8::MAX).to_dec(), dec!(255));
+    assert_eq!(
+        Positive::from(NonZeroU64::MAX).to_dec(),
+        Decimal::from(u64::MAX)
+    );
+    assert_eq!(Positive::from(NonZeroUsize::MIN).to_dec(), dec!(1));
+}
+
+#[test]
+fn test_signed_try_from() {
+    assert_eq!(Positive::try_from(42_i8).unwrap().to_dec(), dec!(42));
+    assert_eq!(Positive::try_from(42_i16).unwrap().to_dec(), dec!(42));
+    assert_eq!(Positive::try_from(42_i32).unwrap().to_dec(), dec!(42));
+    assert_eq!(Positive::try_from(42_isize).unwrap().to_dec(), dec!(42));
+
+    let error = Positive::try_from(-3_i32).unwrap_err();
+    assert_eq!(error.kind(), PositiveErrorKind::Negative);
+    assert_eq!(error.value(), Some(dec!(-3)));
+}
+
+#[test]
+fn test_non_zero_signed_try_from() {
+    assert_eq!(
+        Positive::try_from(NonZeroI64::MAX).unwrap().to_dec(),
+        Decimal::from(i64::MAX)
+    );
+    assert_eq!(
+        Positive::try_from(NonZeroI8::MIN).unwrap_err().kind(),
+        PositiveErrorKind::Negative
+    );
+}
+
+#[test]
+fn test_wide_integers_within_range() {
+    let max = Decimal::MAX.mantissa();
+    assert_eq!(Positive::try_from(max).unwrap(), Positive::MAX);
+    assert_eq!(Positive::try_from(max as u128).unwrap(), Positive::MAX);
+    assert_eq!(
+        Positive::try_from(NonZeroU128::new(12).unwrap())
+            .unwrap()
+            .to_dec(),
+        dec!(12)
+    );
+    assert_eq!(
+        Positive::try_from(NonZeroI128::new(12).unwrap())
+            .unwrap()
+            .to_dec(),
+        dec!(12)
+    );
+    assert_eq!(
+        Positive::try_from(-1_i128).unwrap_err().kind(),
+        PositiveErrorKind::Negative
+    );
+}
+
+#[test]
+fn test_wide_integers_overflow() {
+    let too_large = Decimal::MAX.mantissa() + 1;
+    assert_eq!(
+        Positive::try_from(too_large).unwrap_err().kind(),
+        PositiveErrorKind::Overflow
+    );
+    assert_eq!(
+        Positive::try_from(u128::MAX).unwrap_err().kind(),
+        PositiveErrorKind::Overflow
+    );
+    assert_eq!(
+        Positive::try_from(NonZeroU128::MAX).unwrap_err().kind(),
+        PositiveErrorKind::Overflow
+    );
+    assert_eq!(
+        Positive::try_from(u128::MAX).unwrap_err().to_string(),
+        format!(
+            "Failed to convert from u128 to Positive: {} is out of range for Decimal",
+            u128::MAX
+        )
+    );
+}
+
+// ============================================================================
+// Positive into integers
+// ============================================================================
+
+#[test]
+fn test_to_integer_truncates() {
+    let value = pos_or_panic!(42.9);
+    assert_eq!(value.to_u8(), 42);
+    assert_eq!(value.to_u16(), 42);
+    assert_eq!(value.to_u32(), 42);
+    assert_eq!(value.to_u64(), 42);
+    assert_eq!(value.to_u128(), 42);
+    assert_eq!(value.to_usize(), 42);
+    assert_eq!(value.to_i64(), 42);
+    assert_eq!(value.to_i128(), 42);
+    assert_eq!(value.to_non_zero_u64(), NonZeroU64::new(42).unwrap());
+    assert_eq!(value.to_non_zero_usize(), NonZeroUsize::new(42).unwrap());
+}
+
+#[test]
+fn test_to_integer_checked_out_of_range() {
+    let value = pos_or_panic!(300.0);
+    assert_eq!(value.to_u8_checked(), None);
+    assert_eq!(value.to_u16_checked(), Some(300));
+    assert_eq!(Positive::MAX.to_u64_checked(), None);
+    assert_eq!(
+        Positive::MAX.to_u128_checked(),
+        Some(Decimal::MAX.mantissa() as u128)
+    );
+    assert_eq!(
+        Positive::MAX.to_i128_checked(),
+        Some(Decimal::MAX.mantissa())
+    );
+}
+
+#[test]
+#[should_panic(expected = "Decimal to u8 conversion failed - value out of range")]
+fn test_to_integer_panics_out_of_range() {
+    let _ = pos_or_panic!(256.0).to_u8();
+}
+
+#[test]
+fn test_to_integer_rounded() {
+    let value = pos_or_panic!(2.5);
+    assert_eq!(
+        value.to_u64_rounded(RoundingStrategy::MidpointNearestEven),
+        Some(2)
+    );
+    assert_eq!(
+        value.to_u64_rounded(RoundingStrategy::MidpointAwayFromZero),
+        Some(3)
+    );
+    assert_eq!(value.to_u8_rounded(RoundingStrategy::ToZero), Some(2));
+    assert_eq!(
+        value.to_i128_rounded(RoundingStrategy::AwayFromZero),
+        Some(3)
+    );
+    assert_eq!(
+        pos_or_panic!(255.5).to_u8_rounded(RoundingStrategy::MidpointAwayFromZero),
+        None
+    );
+}
+
+#[test]
+fn test_to_non_zero_rejects_zero() {
+    let half = pos_or_panic!(0.5);
+    assert_eq!(half.to_non_zero_u64_checked(), None);
+    assert_eq!(
+        half.to_non_zero_u64_rounded(RoundingStrategy::AwayFromZero),
+        NonZeroU64::new(1)
+    );
+    assert_eq!(
+        NonZeroU64::try_from(half).unwrap_err().kind(),
+        PositiveErrorKind::PrecisionLoss
+    );
+    assert_eq!(
+        NonZeroUsize::try_from(pos_or_panic!(7.0)).unwrap(),
+        NonZeroUsize::new(7).unwrap()
+    );
+}
+
+#[test]
+#[cfg(not(feature = "non-zero"))]
+fn test_try_into_non_zero_from_zero() {
+    assert_eq!(
+        NonZeroU64::try_from(Positive::ZERO).unwrap_err().kind(),
+        PositiveErrorKind::Zero
+    );
+}
+
+#[test]
+fn test_try_from_positive_is_exact() {
+    assert_eq!(u8::try_from(pos_or_panic!(200.0)).unwrap(), 200);
+    assert_eq!(i128::try_from(pos_or_panic!(200.0)).unwrap(), 200);
+
+    let error = u32::try_from(pos_or_panic!(1.5)).unwrap_err();
+    assert_eq!(error.kind(), PositiveErrorKind::PrecisionLoss);
+    assert_eq!(error.code(), "conversion.precision_loss");
+
+    let error = u32::try_from(Positive::MAX).unwrap_err();
+    assert_eq!(error.kind(), PositiveErrorKind::Overflow);
+}
+
+#[test]
+fn test_from_positive_for_u64_and_usize_truncates() {
+    assert_eq!(u64::from(pos_or_panic!(42.9)), 42);
+    assert_eq!(usize::from(pos_or_panic!(42.9)), 42);
+    assert_eq!(u64::from(Positive::MAX), 0);
+    assert_eq!(usize::from(Positive::MAX), 0);
+}
+
+#[test]
+#[cfg(target_pointer_width = "64")]
+fn test_from_positive_for_usize_is_exact_above_f64_precision() {
+    let value = Positive::new_decimal(dec!(9007199254740993)).unwrap();
+    assert_eq!(usize::from(value), 9_007_199_254_740_993);
+    assert_eq!(u64::from(value), 9_007_199_254_740_993);
+}
+
+#[test]
+fn test_to_decimal_string_keeps_scale() {
+    let value = Positive::new_decimal(dec!(1.50)).unwrap();
+    assert_eq!(value.to_decimal_string(), "1.50");
+    assert_eq!(value.to_string(), "1.5");
+    assert_eq!(
+        Positive::new_decimal(dec!(100))
+            .unwrap()
+            .to_decimal_string(),
+        "100"
+    );
+}
+
+#[test]
+fn test_to_f32() {
+    let value = pos_or_panic!(0.1);
+    assert_eq!(value.to_f32(), 0.1_f32);
+    assert_eq!(value.to_f32_checked(), Some(0.1_f32));
+    assert_eq!(f32::try_from(value).unwrap(), 0.1_f32);
+    assert!(Positive::MAX.to_f32().is_finite());
+}
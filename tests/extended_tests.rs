@@ -0,0 +1,188 @@
+/******************************************************************************
+   Author: Joaquín Béjar García
+   Email: jb@taunais.com
+   Date: 18/10/26
+******************************************************************************/
+
+use positive::constants::EXT_INFINITY;
+use positive::{Positive, PositiveError, PositiveExt, pos_or_panic};
+use std::str::FromStr;
+
+fn finite(value: f64) -> PositiveExt {
+    PositiveExt::from(pos_or_panic!(value))
+}
+
+fn assert_arithmetic_error(result: positive::PositiveResult<PositiveExt>, operation: &str) {
+    match result {
+        Err(PositiveError::ArithmeticError { operation: op, .. }) => assert_eq!(op, operation),
+        other => panic!("expected ArithmeticError for {operation}, got {other:?}"),
+    }
+}
+
+// ============================================================================
+// Construction and inspection
+// ============================================================================
+
+#[test]
+fn test_infinity_constant() {
+    assert_eq!(EXT_INFINITY, PositiveExt::INFINITY);
+    assert!(EXT_INFINITY.is_infinite());
+    assert!(!EXT_INFINITY.is_finite());
+    assert_eq!(EXT_INFINITY.finite(), None);
+    assert_eq!(EXT_INFINITY.to_f64(), f64::INFINITY);
+}
+
+#[test]
+fn test_new_from_f64() {
+    assert_eq!(
+        PositiveExt::new(f64::INFINITY).unwrap(),
+        PositiveExt::Infinite
+    );
+    assert_eq!(PositiveExt::new(2.5).unwrap(), pos_or_panic!(2.5));
+    assert!(PositiveExt::new(-1.0).is_err());
+    assert!(PositiveExt::new(f64::NAN).is_err());
+    assert!(PositiveExt::new(f64::NEG_INFINITY).is_err());
+}
+
+#[test]
+fn test_try_from_into_positive() {
+    assert_eq!(Positive::try_from(finite(3.0)).unwrap(), pos_or_panic!(3.0));
+    assert!(matches!(
+        Positive::try_from(EXT_INFINITY),
+        Err(PositiveError::ConversionError { .. })
+    ));
+}
+
+#[test]
+fn test_ordering() {
+    assert!(EXT_INFINITY > Positive::MAX);
+    assert!(Positive::MAX < EXT_INFINITY);
+    assert!(finite(1.0) < finite(2.0));
+    assert_eq!(finite(1.0).max(EXT_INFINITY), EXT_INFINITY);
+    assert_eq!(finite(1.0).min(EXT_INFINITY), finite(1.0));
+}
+
+// ============================================================================
+// Arithmetic rules
+// ============================================================================
+
+#[test]
+fn test_add() {
+    assert_eq!(finite(1.5) + pos_or_panic!(2.5), pos_or_panic!(4.0));
+    assert!((EXT_INFINITY + Positive::ONE).is_infinite());
+    assert!((finite(1.0) + EXT_INFINITY).is_infinite());
+    assert!((EXT_INFINITY + EXT_INFINITY).is_infinite());
+}
+
+#[test]
+fn test_add_overflow_becomes_infinite() {
+    assert!((PositiveExt::from(Positive::MAX) + Positive::MAX).is_infinite());
+}
+
+#[test]
+fn test_sub() {
+    assert_eq!(finite(5.0) - pos_or_panic!(2.0), pos_or_panic!(3.0));
+    assert!((EXT_INFINITY - Positive::MAX).is_infinite());
+    assert_arithmetic_error(finite(1.0).checked_sub(&EXT_INFINITY), "subtraction");
+    assert_arithmetic_error(EXT_INFINITY.checked_sub(&EXT_INFINITY), "subtraction");
+    assert_arithmetic_error(finite(1.0).checked_sub(&finite(2.0)), "subtraction");
+}
+
+#[test]
+fn test_mul() {
+    assert_eq!(finite(1.5) * pos_or_panic!(2.0), pos_or_panic!(3.0));
+    assert!((EXT_INFINITY * Positive::TWO).is_infinite());
+    assert!((EXT_INFINITY * EXT_INFINITY).is_infinite());
+    assert!((PositiveExt::from(Positive::MAX) * Positive::TWO).is_infinite());
+}
+
+#[cfg(not(feature = "non-zero"))]
+#[test]
+fn test_mul_infinity_by_zero_is_undefined() {
+    assert_arithmetic_error(EXT_INFINITY.checked_mul(&finite(0.0)), "multiplication");
+    assert_arithmetic_error(finite(0.0).checked_mul(&EXT_INFINITY), "multiplication");
+}
+
+#[test]
+fn test_div() {
+    assert_eq!(finite(3.0) / pos_or_panic!(2.0), pos_or_panic!(1.5));
+    assert!((EXT_INFINITY / Positive::TWO).is_infinite());
+    assert_arithmetic_error(EXT_INFINITY.checked_div(&EXT_INFINITY), "division");
+}
+
+#[cfg(not(feature = "non-zero"))]
+#[test]
+fn test_div_by_infinity_and_zero() {
+    assert_eq!(finite(3.0) / EXT_INFINITY, Positive::ZERO);
+    assert_arithmetic_error(finite(3.0).checked_div(&finite(0.0)), "division");
+    assert_arithmetic_error(EXT_INFINITY.checked_div(&finite(0.0)), "division");
+}
+
+#[cfg(feature = "non-zero")]
+#[test]
+fn test_div_by_infinity_non_zero() {
+    assert_arithmetic_error(finite(3.0).checked_div(&EXT_INFINITY), "division");
+}
+
+#[test]
+#[should_panic(expected = "Arithmetic error during subtraction")]
+fn test_sub_infinity_from_infinity_panics() {
+    let _ = EXT_INFINITY - EXT_INFINITY;
+}
+
+// ============================================================================
+// Formatting, parsing and serde
+// ============================================================================
+
+#[test]
+fn test_display() {
+    assert_eq!(EXT_INFINITY.to_string(), "inf");
+    assert_eq!(format!("{:>5}", EXT_INFINITY), "  inf");
+    assert_eq!(finite(2.5).to_string(), "2.5");
+}
+
+#[test]
+fn test_from_str() {
+    assert_eq!(PositiveExt::from_str("inf").unwrap(), EXT_INFINITY);
+    assert_eq!(PositiveExt::from_str("+Infinity").unwrap(), EXT_INFINITY);
+    assert_eq!(PositiveExt::from_str("2.5").unwrap(), pos_or_panic!(2.5));
+    assert!(PositiveExt::from_str("-inf").is_err());
+    assert!(PositiveExt::from_str("abc").is_err());
+    assert_eq!(PositiveExt::from_str(" inf ").unwrap(), EXT_INFINITY);
+    assert_eq!(PositiveExt::from_str(" 5 ").unwrap(), pos_or_panic!(5.0));
+}
+
+#[test]
+fn test_serde_roundtrip() {
+    let json = serde_json::to_string(&EXT_INFINITY).unwrap();
+    assert_eq!(json, "\"inf\"");
+    let parsed: PositiveExt = serde_json::from_str(&json).unwrap();
+    assert_eq!(parsed, EXT_INFINITY);
+
+    let json = serde_json::to_string(&finite(2.5)).unwrap();
+    assert_eq!(json, "2.5");
+    let parsed: PositiveExt = serde_json::from_str(&json).unwrap();
+    assert_eq!(parsed, finite(2.5));
+}
+
+#[test]
+fn test_deserialize_integer_and_negative() {
+    let parsed: PositiveExt = serde_json::from_str("42").unwrap();
+    assert_eq!(parsed, pos_or_panic!(42.0));
+    assert!(serde_json::from_str::<PositiveExt>("-1").is_err());
+    assert!(serde_json::from_str::<PositiveExt>("-1.5").is_err());
+}
+
+#[test]
+fn test_positive_rejects_infinite_input() {
+    assert!(serde_json::from_str::<Positive>("\"inf\"").is_err());
+    assert!(serde_json::from_str::<Positive>("1.7976931348623157e+308").is_err());
+}
+
+#[test]
+#[allow(deprecated)]
+fn test_deprecated_infinity_constant_is_max() {
+    let limit: Positive = positive::constants::INFINITY;
+    assert_eq!(limit, Positive::MAX);
+    assert!(PositiveExt::from(limit).is_finite());
+}
@@ -0,0 +1,195 @@
+/******************************************************************************
+   Author: Joaquín Béjar García
+   Email: jb@taunais.com
+   Date: 18/10/26
+******************************************************************************/
+
+use positive::Positive;
+use rust_decimal_macros::dec;
+
+// ============================================================================
+// Display
+// ============================================================================
+
+#[test]
+fn test_display_width_fill_and_alignment() {
+    let value = Positive::new_decimal(dec!(12.5)).unwrap();
+    assert_eq!(format!("{value:8}"), "    12.5");
+    assert_eq!(format!("{value:<8}|"), "12.5    |");
+    assert_eq!(format!("{value:*^8}"), "**12.5**");
+    assert_eq!(format!("{value:>8.2}"), "   12.50");
+    assert_eq!(format!("{value:08.3}"), "0012.500");
+    assert_eq!(format!("{value:2}"), "12.5");
+}
+
+#[test]
+fn test_display_plus_flag() {
+    let value = Positive::new_decimal(dec!(12.5)).unwrap();
+    assert_eq!(format!("{value:+}"), "+12.5");
+    assert_eq!(format!("{value:+08}"), "+00012.5");
+}
+
+#[test]
+fn test_display_alternate_keeps_scale() {
+    let value = Positive::new_decimal(dec!(1.500)).unwrap();
+    assert_eq!(format!("{value}"), "1.5");
+    assert_eq!(format!("{value:#}"), "1.500");
+    assert_eq!(format!("{value:>#7}"), "  1.500");
+    assert_eq!(format!("{value:#>7}"), "####1.5");
+}
+
+#[test]
+fn test_display_precision_on_integers() {
+    let value = Positive::new_decimal(dec!(42)).unwrap();
+    assert_eq!(format!("{value}"), "42");
+    assert_eq!(format!("{value:.2}"), "42.00");
+}
+
+#[test]
+fn test_display_precision_rounds_half_to_even() {
+    let value = Positive::new_decimal(dec!(0.35)).unwrap();
+    assert_eq!(format!("{value:.1}"), "0.4");
+    assert_eq!(format!("{value:.4}"), "0.3500");
+    let value = Positive::new_decimal(dec!(0.25)).unwrap();
+    assert_eq!(format!("{value:.1}"), "0.2");
+    assert_eq!(format!("{value:.1?}"), "0.2");
+    let value = Positive::new_decimal(dec!(4.578)).unwrap();
+    assert_eq!(format!("{value:.2}"), "4.58");
+    assert_eq!(format!("{value:.2?}"), "4.58");
+    assert_eq!(format!("{value:.2}"), value.format_fixed_places(2));
+}
+
+#[test]
+fn test_display_high_scale_and_large_values() {
+    let tiny = Positive::new_decimal(dec!(0.0000000000000000000000000001)).unwrap();
+    assert_eq!(format!("{tiny}"), "0.0000000000000000000000000001");
+    assert_eq!(format!("{:.30}", tiny), "0.000000000000000000000000000100");
+    assert_eq!(
+        format!("{}", Positive::MAX),
+        "79228162514264337593543950335"
+    );
+    assert_eq!(
+        format!("{:.2}", Positive::MAX),
+        "79228162514264337593543950335.00"
+    );
+}
+
+// ============================================================================
+// Debug
+// ============================================================================
+
+#[test]
+fn test_debug_keeps_scale_and_pads() {
+    let value = Positive::new_decimal(dec!(1.50)).unwrap();
+    assert_eq!(format!("{value:?}"), "1.50");
+    assert_eq!(format!("{value:>6?}"), "  1.50");
+    assert_eq!(format!("{value:.3?}"), "1.500");
+    assert_eq!(format!("{:?}", Some(value)), "Some(1.50)");
+}
+
+// ============================================================================
+// Scientific notation
+// ============================================================================
+
+#[test]
+fn test_lower_exp() {
+    assert_eq!(
+        format!("{:e}", Positive::new_decimal(dec!(1234.5)).unwrap()),
+        "1.2345e3"
+    );
+    assert_eq!(
+        format!("{:e}", Positive::new_decimal(dec!(0.00012)).unwrap()),
+        "1.2e-4"
+    );
+    assert_eq!(
+        format!("{:e}", Positive::new_decimal(dec!(100.00)).unwrap()),
+        "1e2"
+    );
+    assert_eq!(
+        format!("{:e}", Positive::new_decimal(dec!(7)).unwrap()),
+        "7e0"
+    );
+    assert_eq!(
+        format!("{:.2e}", Positive::new_decimal(dec!(1234.5)).unwrap()),
+        "1.23e3"
+    );
+    assert_eq!(
+        format!("{:.3e}", Positive::new_decimal(dec!(5)).unwrap()),
+        "5.000e0"
+    );
+    assert_eq!(
+        format!("{:e}", Positive::MAX),
+        "7.9228162514264337593543950335e28"
+    );
+}
+
+#[test]
+fn test_exp_precision_rounds_like_f64() {
+    let value = Positive::new_decimal(dec!(9.9)).unwrap();
+    assert_eq!(format!("{value:.0e}"), "1e1");
+    assert_eq!(format!("{value:.0e}"), format!("{:.0e}", 9.9_f64));
+    assert_eq!(
+        format!("{:.2e}", Positive::new_decimal(dec!(1.235)).unwrap()),
+        "1.24e0"
+    );
+    assert_eq!(
+        format!("{:.2e}", Positive::new_decimal(dec!(1.225)).unwrap()),
+        "1.22e0"
+    );
+    assert_eq!(
+        format!("{:.1e}", Positive::new_decimal(dec!(0.0996)).unwrap()),
+        "1.0e-1"
+    );
+    assert_eq!(format!("{:.0e}", Positive::MAX), "8e28");
+}
+
+#[test]
+fn test_upper_exp_with_padding() {
+    let value = Positive::new_decimal(dec!(0.00012)).unwrap();
+    assert_eq!(format!("{value:E}"), "1.2E-4");
+    assert_eq!(format!("{value:>10E}"), "    1.2E-4");
+    assert_eq!(format!("{value:+.2E}"), "+1.20E-4");
+}
+
+#[test]
+#[cfg(not(feature = "non-zero"))]
+fn test_exp_zero() {
+    assert_eq!(format!("{:e}", Positive::ZERO), "0e0");
+    assert_eq!(format!("{:.2e}", Positive::ZERO), "0.00e0");
+}
+
+// ============================================================================
+// format_fixed_places
+// ============================================================================
+
+#[test]
+fn test_format_fixed_places_is_exact() {
+    assert_eq!(
+        Positive::new_decimal(dec!(2.5))
+            .unwrap()
+            .format_fixed_places(3),
+        "2.500"
+    );
+    assert_eq!(
+        Positive::new_decimal(dec!(2.345))
+            .unwrap()
+            .format_fixed_places(2),
+        "2.34"
+    );
+    assert_eq!(
+        Positive::new_decimal(dec!(2.355))
+            .unwrap()
+            .format_fixed_places(2),
+        "2.36"
+    );
+    assert_eq!(
+        Positive::new_decimal(dec!(12345678901234567890.123456789))
+            .unwrap()
+            .format_fixed_places(4),
+        "12345678901234567890.1235"
+    );
+    assert_eq!(
+        Positive::MAX.format_fixed_places(1),
+        "79228162514264337593543950335.0"
+    );
+}
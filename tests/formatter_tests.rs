@@ -0,0 +1,262 @@
+/******************************************************************************
+   Author: Joaquín Béjar García
+   Email: jb@taunais.com
+   Date: 18/10/26
+******************************************************************************/
+
+use positive::{Positive, PositiveError, PositiveErrorKind, PositiveFormatter};
+use rust_decimal::RoundingStrategy;
+use rust_decimal_macros::dec;
+
+fn us() -> PositiveFormatter {
+    PositiveFormatter::new()
+        .grouping_separator(',')
+        .fraction_digits(2, 2)
+}
+
+fn eu() -> PositiveFormatter {
+    PositiveFormatter::new()
+        .grouping_separator('.')
+        .decimal_separator(',')
+        .fraction_digits(2, 2)
+}
+
+// ============================================================================
+// Formatting
+// ============================================================================
+
+#[test]
+fn test_default_matches_display() {
+    let formatter = PositiveFormatter::default();
+    for value in [dec!(1234567.8900), dec!(0.001), dec!(42)] {
+        let value = Positive::new_decimal(value).unwrap();
+        assert_eq!(formatter.format(&value), value.to_string());
+    }
+}
+
+#[test]
+fn test_grouping_and_separators() {
+    let value = Positive::new_decimal(dec!(1234567.891)).unwrap();
+    assert_eq!(us().format(&value), "1,234,567.89");
+    assert_eq!(eu().format(&value), "1.234.567,89");
+    assert_eq!(
+        us().format(&Positive::new_decimal(dec!(123)).unwrap()),
+        "123.00"
+    );
+    assert_eq!(
+        us().format(&Positive::new_decimal(dec!(1000)).unwrap()),
+        "1,000.00"
+    );
+}
+
+#[test]
+fn test_group_size() {
+    let value = Positive::new_decimal(dec!(12345678)).unwrap();
+    let indian = PositiveFormatter::new()
+        .grouping_separator(' ')
+        .group_size(4);
+    assert_eq!(indian.format(&value), "1234 5678");
+    let ungrouped = PositiveFormatter::new()
+        .grouping_separator(',')
+        .group_size(0);
+    assert_eq!(ungrouped.format(&value), "12345678");
+}
+
+#[test]
+fn test_fraction_digit_range() {
+    let formatter = PositiveFormatter::new().fraction_digits(1, 3);
+    assert_eq!(
+        formatter.format(&Positive::new_decimal(dec!(2)).unwrap()),
+        "2.0"
+    );
+    assert_eq!(
+        formatter.format(&Positive::new_decimal(dec!(2.50)).unwrap()),
+        "2.5"
+    );
+    assert_eq!(
+        formatter.format(&Positive::new_decimal(dec!(2.12345)).unwrap()),
+        "2.123"
+    );
+    let formatter = PositiveFormatter::new().fraction_digits(4, 1);
+    assert_eq!(
+        formatter.format(&Positive::new_decimal(dec!(2.5)).unwrap()),
+        "2.5000"
+    );
+}
+
+#[test]
+fn test_rounding_strategy() {
+    let value = Positive::new_decimal(dec!(2.345)).unwrap();
+    let formatter = PositiveFormatter::new().fraction_digits(0, 2);
+    assert_eq!(formatter.format(&value), "2.34");
+    assert_eq!(
+        formatter
+            .clone()
+            .rounding(RoundingStrategy::MidpointAwayFromZero)
+            .format(&value),
+        "2.35"
+    );
+    assert_eq!(
+        formatter
+            .rounding(RoundingStrategy::ToZero)
+            .format(&Positive::new_decimal(dec!(2.349)).unwrap()),
+        "2.34"
+    );
+}
+
+#[test]
+fn test_prefix_and_suffix() {
+    let value = Positive::new_decimal(dec!(1500)).unwrap();
+    assert_eq!(us().prefix("$").format(&value), "$1,500.00");
+    assert_eq!(eu().suffix(" €").format(&value), "1.500,00 €");
+}
+
+#[test]
+fn test_large_values() {
+    assert_eq!(
+        us().format(&Positive::MAX),
+        "79,228,162,514,264,337,593,543,950,335.00"
+    );
+}
+
+// ============================================================================
+// Parsing
+// ============================================================================
+
+#[test]
+fn test_round_trip() {
+    for formatter in [
+        us().prefix("$"),
+        eu().suffix(" €"),
+        PositiveFormatter::new().fraction_digits(0, 28),
+        PositiveFormatter::new()
+            .grouping_separator('\'')
+            .group_size(4),
+    ] {
+        let parser = formatter.parser().unwrap();
+        for value in [dec!(0.5), dec!(1234567.89), dec!(1000), dec!(7)] {
+            let value = Positive::new_decimal(value).unwrap();
+            let text = formatter.format(&value);
+            assert_eq!(parser.parse(&text).unwrap(), value, "{text}");
+        }
+    }
+}
+
+#[test]
+fn test_parse_optional_parts() {
+    let parser = us().prefix("$").parser().unwrap();
+    assert_eq!(
+        parser.parse("1234.5").unwrap(),
+        Positive::new_decimal(dec!(1234.5)).unwrap()
+    );
+    assert_eq!(
+        parser.parse("$1,234").unwrap(),
+        Positive::new_decimal(dec!(1234)).unwrap()
+    );
+    assert_eq!(
+        parser.parse(".25").unwrap(),
+        Positive::new_decimal(dec!(0.25)).unwrap()
+    );
+    assert_eq!(
+        parser.parse("3.14159").unwrap(),
+        Positive::new_decimal(dec!(3.14159)).unwrap()
+    );
+}
+
+#[test]
+fn test_parse_keeps_scale() {
+    let parser = us().parser().unwrap();
+    assert_eq!(format!("{:?}", parser.parse("1.50").unwrap()), "1.50");
+    assert_eq!(format!("{:?}", parser.parse("1,000").unwrap()), "1000");
+}
+
+#[test]
+fn test_parse_errors() {
+    let parser = us().prefix("$").parser().unwrap();
+    let error = |input: &str| match parser.parse(input).unwrap_err() {
+        PositiveError::ParseError {
+            position, reason, ..
+        } => (position, reason),
+        other => panic!("unexpected error {other:?}"),
+    };
+    let misplaced = "misplaced grouping separator".to_string();
+    assert_eq!(error("1,23"), (4, misplaced.clone()));
+    assert_eq!(error("12,34,567"), (5, misplaced.clone()));
+    assert_eq!(error("1234,567"), (4, misplaced.clone()));
+    assert_eq!(error(",123"), (0, misplaced.clone()));
+    assert_eq!(error("1,2345.5"), (6, misplaced));
+    assert_eq!(error("1.2.3"), (3, "unexpected character '.'".to_string()));
+    assert_eq!(error("$1x"), (2, "unexpected character 'x'".to_string()));
+    assert_eq!(error("$"), (1, "expected a digit".to_string()));
+    assert_eq!(error("-1"), (0, "unexpected character '-'".to_string()));
+}
+
+#[test]
+#[cfg(not(feature = "non-zero"))]
+fn test_parse_zero() {
+    assert!(us().parser().unwrap().parse("0.00").unwrap().is_zero());
+}
+
+#[test]
+#[cfg(feature = "non-zero")]
+fn test_parse_zero_rejected() {
+    assert!(us().parser().unwrap().parse("0.00").is_err());
+}
+
+#[test]
+fn test_ambiguous_separators() {
+    let same = PositiveFormatter::new().grouping_separator('.');
+    let err = same.parser().unwrap_err();
+    assert!(matches!(
+        err,
+        PositiveError::InvalidValue { value: None, .. }
+    ));
+    assert_eq!(err.kind(), PositiveErrorKind::Other);
+    assert_eq!(err.code(), "invalid_value.other");
+    let digit = PositiveFormatter::new().decimal_separator('1');
+    assert_eq!(digit.parser().unwrap_err().code(), "invalid_value.other");
+}
+
+#[test]
+fn test_ambiguous_prefix_and_suffix() {
+    // A digit suffix runs into the number: 12 would print as "125".
+    let digit = PositiveFormatter::new().suffix("5");
+    assert_eq!(
+        digit.format(&Positive::new_decimal(dec!(12)).unwrap()),
+        "125"
+    );
+    let err = digit.parser().unwrap_err();
+    assert_eq!(err.kind(), PositiveErrorKind::Other);
+    assert_eq!(err.code(), "invalid_value.other");
+
+    for formatter in [
+        us().prefix("1$"),
+        us().suffix(" p.a."),
+        eu().prefix("EUR,"),
+        PositiveFormatter::new().prefix("."),
+    ] {
+        assert_eq!(
+            formatter.parser().unwrap_err().code(),
+            "invalid_value.other",
+            "{formatter:?}"
+        );
+    }
+
+    let safe = us().prefix("USD ").suffix(" pa");
+    let value = Positive::new_decimal(dec!(1234.5)).unwrap();
+    let text = safe.format(&value);
+    assert_eq!(text, "USD 1,234.50 pa");
+    assert_eq!(safe.parser().unwrap().parse(&text).unwrap(), value);
+}
+
+#[test]
+fn test_parse_too_many_decimal_places() {
+    let parser = us().parser().unwrap();
+    assert_eq!(
+        parser
+            .parse("0.00000000000000000000000000001")
+            .unwrap_err()
+            .kind(),
+        PositiveErrorKind::PrecisionLoss
+    );
+}
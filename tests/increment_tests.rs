@@ -0,0 +1,257 @@
+/******************************************************************************
+   Author: Joaquín Béjar García
+   Email: jb@taunais.com
+   Date: 18/10/26
+******************************************************************************/
+
+use positive::{
+    Increment, Positive, PositiveError, PositiveErrorKind, RoundingStrategy, TickSize, pos_or_panic,
+};
+use rust_decimal::Decimal;
+use rust_decimal_macros::dec;
+
+// ============================================================================
+// Increment
+// ============================================================================
+
+#[test]
+#[cfg(not(feature = "non-zero"))]
+fn test_zero_step_is_rejected() {
+    let error = Increment::new(Positive::ZERO).unwrap_err();
+    assert_eq!(error.kind(), PositiveErrorKind::Zero);
+    assert!(Increment::try_from(Positive::ZERO).is_err());
+    assert_eq!(
+        Positive::ONE
+            .round_to_increment(Positive::ZERO, RoundingStrategy::ToZero)
+            .unwrap_err()
+            .kind(),
+        PositiveErrorKind::Zero
+    );
+    assert!(Positive::ONE.floor_to_increment(Positive::ZERO).is_err());
+    assert!(Positive::ONE.ceil_to_increment(Positive::ZERO).is_err());
+}
+
+#[test]
+fn test_step_round_trip() {
+    let increment = Increment::try_from(pos_or_panic!(0.25)).unwrap();
+    assert_eq!(increment.step(), dec!(0.25));
+    assert_eq!(Positive::from(increment), dec!(0.25));
+}
+
+#[test]
+fn test_is_aligned() {
+    let tick = TickSize::new(pos_or_panic!(0.05)).unwrap();
+    assert!(tick.is_aligned(pos_or_panic!(101.25)));
+    assert!(tick.is_aligned(pos_or_panic!(101.2000)));
+    assert!(!tick.is_aligned(pos_or_panic!(101.23)));
+    assert!(!tick.is_aligned(pos_or_panic!(101.2500001)));
+}
+
+// ============================================================================
+// Snapping
+// ============================================================================
+
+#[test]
+fn test_floor_and_ceil() {
+    let tick = TickSize::new(pos_or_panic!(0.05)).unwrap();
+    assert_eq!(tick.floor(pos_or_panic!(101.23)).unwrap(), dec!(101.2));
+    assert_eq!(tick.ceil(pos_or_panic!(101.23)).unwrap(), dec!(101.25));
+    assert_eq!(tick.floor(pos_or_panic!(101.25)).unwrap(), dec!(101.25));
+    assert_eq!(tick.ceil(pos_or_panic!(101.25)).unwrap(), dec!(101.25));
+
+    let lot = pos_or_panic!(100.0);
+    assert_eq!(
+        pos_or_panic!(1250.0).floor_to_increment(lot).unwrap(),
+        dec!(1200)
+    );
+    assert_eq!(
+        pos_or_panic!(1250.0).ceil_to_increment(lot).unwrap(),
+        dec!(1300)
+    );
+}
+
+#[test]
+fn test_round_with_every_strategy() {
+    let step = pos_or_panic!(0.25);
+    let round = |value: Decimal, strategy| {
+        Positive::new_decimal(value)
+            .unwrap()
+            .round_to_increment(step, strategy)
+            .unwrap()
+            .to_dec()
+    };
+    // 1.375 is the midpoint between 1.25 (odd multiple) and 1.5 (even).
+    assert_eq!(
+        round(dec!(1.375), RoundingStrategy::MidpointNearestEven),
+        dec!(1.5)
+    );
+    assert_eq!(
+        round(dec!(1.125), RoundingStrategy::MidpointNearestEven),
+        dec!(1)
+    );
+    assert_eq!(
+        round(dec!(1.375), RoundingStrategy::MidpointAwayFromZero),
+        dec!(1.5)
+    );
+    assert_eq!(
+        round(dec!(1.375), RoundingStrategy::MidpointTowardZero),
+        dec!(1.25)
+    );
+    assert_eq!(
+        round(dec!(1.3), RoundingStrategy::MidpointTowardZero),
+        dec!(1.25)
+    );
+    assert_eq!(
+        round(dec!(1.45), RoundingStrategy::MidpointTowardZero),
+        dec!(1.5)
+    );
+    assert_eq!(round(dec!(1.26), RoundingStrategy::ToZero), dec!(1.25));
+    assert_eq!(
+        round(dec!(1.26), RoundingStrategy::ToNegativeInfinity),
+        dec!(1.25)
+    );
+    assert_eq!(round(dec!(1.26), RoundingStrategy::AwayFromZero), dec!(1.5));
+    assert_eq!(
+        round(dec!(1.26), RoundingStrategy::ToPositiveInfinity),
+        dec!(1.5)
+    );
+}
+
+#[test]
+fn test_round_matches_decimal_rounding() {
+    let strategies = [
+        RoundingStrategy::MidpointNearestEven,
+        RoundingStrategy::MidpointAwayFromZero,
+        RoundingStrategy::MidpointTowardZero,
+        RoundingStrategy::ToZero,
+        RoundingStrategy::AwayFromZero,
+    ];
+    let step = pos_or_panic!(0.01);
+    for value in [dec!(2.345), dec!(2.355), dec!(2.3449), dec!(0.005), dec!(7)] {
+        for strategy in strategies {
+            let expected = value.round_dp_with_strategy(2, strategy);
+            if expected.is_zero() && cfg!(feature = "non-zero") {
+                continue;
+            }
+            assert_eq!(
+                Positive::new_decimal(value)
+                    .unwrap()
+                    .round_to_increment(step, strategy)
+                    .unwrap(),
+                expected,
+                "{value} with {strategy:?}"
+            );
+        }
+    }
+}
+
+#[test]
+fn test_non_decimal_step() {
+    let tick = TickSize::new(pos_or_panic!(0.3)).unwrap();
+    assert_eq!(tick.floor(pos_or_panic!(1.0)).unwrap(), dec!(0.9));
+    assert_eq!(tick.ceil(pos_or_panic!(1.0)).unwrap(), dec!(1.2));
+    assert_eq!(
+        tick.round(pos_or_panic!(1.05), RoundingStrategy::MidpointNearestEven)
+            .unwrap(),
+        dec!(1.2)
+    );
+}
+
+#[test]
+fn test_extreme_steps() {
+    let tiny = TickSize::new(pos_or_panic!(0.0000000000000000000000000001)).unwrap();
+    assert!(tiny.is_aligned(Positive::MAX));
+    assert_eq!(tiny.floor(Positive::MAX).unwrap(), Positive::MAX);
+
+    let huge = Increment::new(Positive::MAX).unwrap();
+    assert_eq!(huge.ceil(pos_or_panic!(1.0)).unwrap(), Positive::MAX);
+    let rounded = huge.round(pos_or_panic!(1.0), RoundingStrategy::MidpointNearestEven);
+    if cfg!(feature = "non-zero") {
+        assert_eq!(rounded.unwrap_err().kind(), PositiveErrorKind::Zero);
+    } else {
+        assert!(rounded.unwrap().is_zero());
+    }
+    assert_eq!(
+        huge.round(
+            Positive::MAX - Positive::ONE,
+            RoundingStrategy::MidpointNearestEven
+        )
+        .unwrap(),
+        Positive::MAX
+    );
+}
+
+#[test]
+fn test_ceil_overflow() {
+    let tick = TickSize::new(pos_or_panic!(10.0)).unwrap();
+    let error = tick.ceil(Positive::MAX).unwrap_err();
+    assert_eq!(error.kind(), PositiveErrorKind::Overflow);
+    assert!(matches!(
+        error,
+        PositiveError::ArithmeticError { ref operation, .. } if operation == "ceil_to_increment"
+    ));
+}
+
+#[test]
+#[cfg(feature = "non-zero")]
+fn test_floor_to_zero_non_zero() {
+    let tick = TickSize::new(pos_or_panic!(0.05)).unwrap();
+    assert_eq!(
+        tick.floor(pos_or_panic!(0.01)).unwrap_err().kind(),
+        PositiveErrorKind::Zero
+    );
+}
+
+#[test]
+#[cfg(not(feature = "non-zero"))]
+fn test_floor_to_zero() {
+    let tick = TickSize::new(pos_or_panic!(0.05)).unwrap();
+    assert!(tick.floor(pos_or_panic!(0.01)).unwrap().is_zero());
+}
+
+// ============================================================================
+// Neighbouring grid points
+// ============================================================================
+
+#[test]
+fn test_next_above_and_below() {
+    let tick = TickSize::new(pos_or_panic!(0.05)).unwrap();
+    let off_grid = pos_or_panic!(101.23);
+    assert_eq!(tick.next_above(off_grid).unwrap(), dec!(101.25));
+    assert_eq!(tick.next_below(off_grid).unwrap(), dec!(101.2));
+
+    let on_grid = pos_or_panic!(101.25);
+    assert_eq!(tick.next_above(on_grid).unwrap(), dec!(101.3));
+    assert_eq!(tick.next_below(on_grid).unwrap(), dec!(101.2));
+}
+
+#[test]
+fn test_points_above() {
+    let tick = TickSize::new(pos_or_panic!(0.5)).unwrap();
+    let points: Vec<_> = tick.points_above(pos_or_panic!(1.2)).take(3).collect();
+    assert_eq!(points, [dec!(1.5), dec!(2), dec!(2.5)]);
+
+    let whole = Increment::new(Positive::ONE).unwrap();
+    let near_max = Positive::new_decimal(Decimal::MAX - dec!(3)).unwrap();
+    assert_eq!(whole.points_above(near_max).count(), 3);
+    // Half steps no longer fit once the integer part uses every digit.
+    assert!(
+        tick.next_above(Positive::new_decimal(Decimal::MAX - dec!(4)).unwrap())
+            .is_err()
+    );
+}
+
+#[test]
+fn test_points_below() {
+    let tick = TickSize::new(pos_or_panic!(0.5)).unwrap();
+    let points: Vec<_> = tick.points_below(pos_or_panic!(1.7)).collect();
+    if cfg!(feature = "non-zero") {
+        assert_eq!(points, [dec!(1.5), dec!(1), dec!(0.5)]);
+    } else {
+        assert_eq!(points, [dec!(1.5), dec!(1), dec!(0.5), dec!(0)]);
+    }
+    assert_eq!(
+        tick.next_below(pos_or_panic!(0.5)).is_some(),
+        !cfg!(feature = "non-zero")
+    );
+}
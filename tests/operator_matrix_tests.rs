@@ -0,0 +1,143 @@
+/******************************************************************************
+   Author: Joaquín Béjar García
+   Email: jb@taunais.com
+   Date: 18/10/26
+******************************************************************************/
+
+//! Compile-time coverage of the binary operator matrix for `Positive`.
+//!
+//! Every test goes through generic helpers bounded on the operator trait, so
+//! the suite only compiles if each `Positive`/`&Positive` × `Positive`/
+//! `&Positive`/`Decimal`/`&Decimal`/`f64` combination is implemented with
+//! `Output = Positive`.
+
+// Borrowed operands are the point of these tests.
+#![allow(clippy::needless_borrows_for_generic_args)]
+
+use positive::{Positive, pos_or_panic};
+use rust_decimal::Decimal;
+use rust_decimal_macros::dec;
+use std::ops::{Add, Div, Mul, Rem, Sub};
+
+fn add<L: Add<R, Output = Positive>, R>(lhs: L, rhs: R) -> Positive {
+    lhs + rhs
+}
+
+fn sub<L: Sub<R, Output = Positive>, R>(lhs: L, rhs: R) -> Positive {
+    lhs - rhs
+}
+
+fn mul<L: Mul<R, Output = Positive>, R>(lhs: L, rhs: R) -> Positive {
+    lhs * rhs
+}
+
+fn div<L: Div<R, Output = Positive>, R>(lhs: L, rhs: R) -> Positive {
+    lhs / rhs
+}
+
+fn rem<L: Rem<R, Output = Positive>, R>(lhs: L, rhs: R) -> Positive {
+    lhs % rhs
+}
+
+/// Applies `$op` to every left/right operand combination and checks the result.
+macro_rules! assert_matrix {
+    ($op:ident, $lhs:expr, $rhs:expr, $expected:expr) => {{
+        let lhs: Positive = $lhs;
+        let rhs: Positive = $rhs;
+        let rhs_dec: Decimal = rhs.into_inner();
+        let rhs_f64: f64 = rhs.to_f64();
+        let expected: Positive = $expected;
+
+        assert_eq!($op(lhs, rhs), expected);
+        assert_eq!($op(lhs, &rhs), expected);
+        assert_eq!($op(lhs, rhs_dec), expected);
+        assert_eq!($op(lhs, &rhs_dec), expected);
+        assert_eq!($op(lhs, rhs_f64), expected);
+
+        assert_eq!($op(&lhs, rhs), expected);
+        assert_eq!($op(&lhs, &rhs), expected);
+        assert_eq!($op(&lhs, rhs_dec), expected);
+        assert_eq!($op(&lhs, &rhs_dec), expected);
+        assert_eq!($op(&lhs, rhs_f64), expected);
+    }};
+}
+
+#[test]
+fn test_add_matrix() {
+    assert_matrix!(
+        add,
+        pos_or_panic!(7.5),
+        pos_or_panic!(2.5),
+        pos_or_panic!(10.0)
+    );
+}
+
+#[test]
+fn test_sub_matrix() {
+    assert_matrix!(
+        sub,
+        pos_or_panic!(7.5),
+        pos_or_panic!(2.5),
+        pos_or_panic!(5.0)
+    );
+}
+
+#[test]
+fn test_mul_matrix() {
+    assert_matrix!(
+        mul,
+        pos_or_panic!(7.5),
+        pos_or_panic!(2.0),
+        pos_or_panic!(15.0)
+    );
+}
+
+#[test]
+fn test_div_matrix() {
+    assert_matrix!(
+        div,
+        pos_or_panic!(7.5),
+        pos_or_panic!(2.5),
+        pos_or_panic!(3.0)
+    );
+}
+
+#[test]
+fn test_rem_matrix() {
+    assert_matrix!(
+        rem,
+        pos_or_panic!(7.5),
+        pos_or_panic!(2.0),
+        pos_or_panic!(1.5)
+    );
+}
+
+#[test]
+#[should_panic(expected = "Arithmetic error during subtraction")]
+fn test_ref_sub_ref_below_zero_panics() {
+    let _ = sub(&Positive::ONE, &Positive::TWO);
+}
+
+#[test]
+#[should_panic(expected = "Arithmetic error during multiplication")]
+fn test_ref_mul_negative_ref_decimal_panics() {
+    let _ = mul(&Positive::ONE, &dec!(-1));
+}
+
+#[test]
+#[should_panic(expected = "Arithmetic error during addition")]
+fn test_ref_add_negative_f64_panics() {
+    let _ = add(&Positive::ONE, -2.0);
+}
+
+#[test]
+#[should_panic(expected = "Arithmetic error during division")]
+fn test_ref_div_zero_decimal_panics() {
+    let _ = div(&Positive::ONE, Decimal::ZERO);
+}
+
+#[test]
+#[should_panic(expected = "Arithmetic error during remainder")]
+fn test_ref_rem_zero_panics() {
+    let _ = rem(&Positive::ONE, &Decimal::ZERO);
+}
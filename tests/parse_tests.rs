@@ -0,0 +1,256 @@
+/******************************************************************************
+   Author: Joaquín Béjar García
+   Email: jb@taunais.com
+   Date: 18/10/26
+******************************************************************************/
+
+use positive::{ParseOptions, Positive, PositiveError, PositiveErrorKind, PositiveResult};
+use rust_decimal_macros::dec;
+use std::str::FromStr;
+
+fn assert_parse_error(result: PositiveResult<Positive>, position: usize) {
+    match result {
+        Err(PositiveError::ParseError { position: at, .. }) => assert_eq!(at, position),
+        other => panic!("expected ParseError at {position}, got {other:?}"),
+    }
+}
+
+// ============================================================================
+// FromStr
+// ============================================================================
+
+#[test]
+fn test_from_str_error_is_positive_error() {
+    fn parse(s: &str) -> PositiveResult<Positive> {
+        let value: Positive = s.parse()?;
+        Ok(value)
+    }
+    assert_eq!(parse("2.5").unwrap(), dec!(2.5));
+    assert_parse_error(parse("2.5x"), 3);
+}
+
+#[test]
+fn test_from_str_keeps_decimal_syntax() {
+    assert_eq!(Positive::from_str("+1.5").unwrap(), dec!(1.5));
+    assert_eq!(Positive::from_str("1_000").unwrap(), dec!(1000));
+    assert_eq!(Positive::from_str("1.5e3").unwrap(), dec!(1500));
+    assert_eq!(Positive::from_str("1.").unwrap(), dec!(1));
+    assert_eq!(Positive::from_str(".5").unwrap(), dec!(0.5));
+}
+
+#[test]
+fn test_from_str_preserves_scale() {
+    assert_eq!(Positive::from_str("5").unwrap().into_inner().scale(), 0);
+    assert_eq!(Positive::from_str("1.50").unwrap().into_inner().scale(), 2);
+    assert_eq!(
+        Positive::from_str("0001.50").unwrap().into_inner().scale(),
+        2
+    );
+}
+
+#[test]
+fn test_from_str_rounds_excess_digits() {
+    let value = Positive::from_str("1.23456789012345678901234567891").unwrap();
+    assert_eq!(value.into_inner(), dec!(1.2345678901234567890123456789));
+}
+
+#[test]
+fn test_from_str_rejects_extensions() {
+    assert_parse_error(Positive::from_str("1,000"), 1);
+    assert_parse_error(Positive::from_str("5%"), 1);
+    assert_parse_error(Positive::from_str("5bp"), 1);
+}
+
+#[test]
+fn test_from_str_error_positions() {
+    assert_parse_error(Positive::from_str(""), 0);
+    assert_parse_error(Positive::from_str(" 1"), 0);
+    assert_parse_error(Positive::from_str("."), 1);
+    assert_parse_error(Positive::from_str("1.2.3"), 3);
+    assert_parse_error(Positive::from_str("_1"), 0);
+    assert_parse_error(Positive::from_str("1e"), 2);
+    assert_parse_error(Positive::from_str("79228162514264337593543950336"), 0);
+    assert_parse_error(Positive::from_str("+79228162514264337593543950336"), 1);
+}
+
+#[test]
+fn test_from_str_negative_is_not_a_parse_error() {
+    assert!(matches!(
+        Positive::from_str("-1.5"),
+        Err(PositiveError::OutOfBounds { .. })
+    ));
+}
+
+// ============================================================================
+// from_str_with
+// ============================================================================
+
+#[test]
+fn test_strict_options() {
+    let strict = ParseOptions::default();
+    assert_eq!(Positive::from_str_with("12.5", strict).unwrap(), dec!(12.5));
+    assert_parse_error(Positive::from_str_with("+12.5", strict), 0);
+    assert_parse_error(Positive::from_str_with("1_2", strict), 1);
+    assert_parse_error(Positive::from_str_with("1e2", strict), 1);
+}
+
+#[test]
+fn test_leading_plus() {
+    let options = ParseOptions {
+        leading_plus: true,
+        ..ParseOptions::default()
+    };
+    assert_eq!(Positive::from_str_with("+7", options).unwrap(), dec!(7));
+    assert_parse_error(Positive::from_str_with("++7", options), 1);
+}
+
+#[test]
+fn test_underscores() {
+    let options = ParseOptions {
+        underscores: true,
+        ..ParseOptions::default()
+    };
+    assert_eq!(
+        Positive::from_str_with("1_000_000.000_1", options).unwrap(),
+        dec!(1000000.0001)
+    );
+    assert_parse_error(Positive::from_str_with("_1", options), 0);
+    assert_parse_error(Positive::from_str_with("1._5", options), 2);
+}
+
+#[test]
+fn test_thousands_separators() {
+    let options = ParseOptions {
+        thousands_separators: true,
+        ..ParseOptions::default()
+    };
+    assert_eq!(
+        Positive::from_str_with("1,234,567.89", options).unwrap(),
+        dec!(1234567.89)
+    );
+    assert_eq!(Positive::from_str_with("999", options).unwrap(), dec!(999));
+    assert_parse_error(Positive::from_str_with(",100", options), 0);
+    assert_parse_error(Positive::from_str_with("1234,567", options), 4);
+    assert_parse_error(Positive::from_str_with("1,23,456", options), 4);
+    assert_parse_error(Positive::from_str_with("1,2345", options), 6);
+    assert_parse_error(Positive::from_str_with("1.234,5", options), 5);
+}
+
+#[test]
+fn test_separators_cannot_touch() {
+    assert_parse_error(Positive::from_str_with("1_,000", ParseOptions::all()), 2);
+    assert_parse_error(Positive::from_str_with("1,_000", ParseOptions::all()), 2);
+    assert_parse_error(Positive::from_str_with("1_000,000", ParseOptions::all()), 5);
+}
+
+#[test]
+fn test_scientific() {
+    let options = ParseOptions {
+        scientific: true,
+        ..ParseOptions::default()
+    };
+    assert_eq!(
+        Positive::from_str_with("1.5e3", options).unwrap(),
+        dec!(1500)
+    );
+    assert_eq!(
+        Positive::from_str_with("2E-4", options).unwrap(),
+        dec!(0.0002)
+    );
+    assert_eq!(Positive::from_str_with("2e+1", options).unwrap(), dec!(20));
+    assert_parse_error(Positive::from_str_with("2e", options), 2);
+    assert_parse_error(Positive::from_str_with("2e1.5", options), 3);
+    assert_parse_error(Positive::from_str_with("1e40", options), 1);
+}
+
+#[test]
+fn test_scientific_underflow_is_rejected() {
+    let options = ParseOptions {
+        scientific: true,
+        ..ParseOptions::default()
+    };
+    assert!(matches!(
+        Positive::from_str_with("1e-40", options),
+        Err(PositiveError::ConversionError {
+            kind: PositiveErrorKind::PrecisionLoss,
+            ..
+        })
+    ));
+    assert_eq!(
+        Positive::from_str_with("1e-28", options).unwrap(),
+        dec!(0.0000000000000000000000000001)
+    );
+}
+
+#[test]
+fn test_too_many_decimal_places_is_rejected() {
+    // `Decimal` itself rounds 29 decimal places of a tiny number to zero.
+    for input in [
+        "0.00000000000000000000000000001",
+        "0.00000000000000000000000000004",
+    ] {
+        assert_eq!(
+            Positive::from_str(input).unwrap_err().kind(),
+            PositiveErrorKind::PrecisionLoss,
+            "{input}"
+        );
+    }
+}
+
+#[test]
+#[cfg(not(feature = "non-zero"))]
+fn test_many_zero_decimal_places_are_zero() {
+    assert!(
+        Positive::from_str("0.00000000000000000000000000000")
+            .unwrap()
+            .is_zero()
+    );
+}
+
+#[test]
+fn test_scientific_is_exact() {
+    let options = ParseOptions {
+        scientific: true,
+        ..ParseOptions::default()
+    };
+    let value = Positive::from_str_with("1.2345e-20", options).unwrap();
+    assert_eq!(value.into_inner(), dec!(0.000000000000000000012345));
+}
+
+#[test]
+fn test_suffixes() {
+    let options = ParseOptions {
+        suffixes: true,
+        ..ParseOptions::default()
+    };
+    assert_eq!(
+        Positive::from_str_with("12.5%", options).unwrap(),
+        dec!(0.125)
+    );
+    assert_eq!(
+        Positive::from_str_with("25bp", options).unwrap(),
+        dec!(0.0025)
+    );
+    assert_eq!(
+        Positive::from_str_with("25bps", options).unwrap(),
+        dec!(0.0025)
+    );
+    assert_parse_error(Positive::from_str_with("25 bp", options), 2);
+    assert_parse_error(Positive::from_str_with("25%%", options), 2);
+    assert_parse_error(Positive::from_str_with("%", options), 0);
+}
+
+#[test]
+fn test_all_options_combined() {
+    let value = Positive::from_str_with("+1,250.5e-2%", ParseOptions::all()).unwrap();
+    assert_eq!(value.into_inner(), dec!(0.1250500));
+    assert_eq!(
+        Positive::from_str_with("+1_000bp", ParseOptions::all()).unwrap(),
+        dec!(0.1)
+    );
+}
+
+#[test]
+fn test_non_ascii_input_reports_byte_offset() {
+    assert_parse_error(Positive::from_str_with("12€", ParseOptions::all()), 2);
+}
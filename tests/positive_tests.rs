@@ -62,9 +62,9 @@ fn test_positive_decimal_debug() {
 #[test]
 fn test_positive_decimal_display_decimal_fix() {
     let p = Positive::new_decimal(dec!(4.578923789423789)).unwrap();
-    assert_eq!(format!("{p:.2}"), "4.57");
-    assert_eq!(format!("{p:.3}"), "4.578");
-    assert_eq!(format!("{p:.0}"), "4");
+    assert_eq!(format!("{p:.2}"), "4.58");
+    assert_eq!(format!("{p:.3}"), "4.579");
+    assert_eq!(format!("{p:.0}"), "5");
 }
 
 #[test]
@@ -517,6 +517,7 @@ fn test_sub_or_none() {
     let a = pos_or_panic!(5.0);
     assert!(a.sub_or_none(&dec!(3.0)).is_some());
     assert!(a.sub_or_none(&dec!(10.0)).is_none());
+    assert!(Positive::MAX.sub_or_none(&dec!(-1)).is_none());
 }
 
 #[test]
@@ -794,6 +795,8 @@ fn test_f64_add_positive() {
 }
 
 #[test]
+// `From` by default, `TryFrom` with `non-zero`.
+#[allow(clippy::unnecessary_fallible_conversions)]
 fn test_try_from_usize() {
     let p: Positive = 42usize.try_into().unwrap();
     assert_eq!(p.to_f64(), 42.0);
@@ -862,10 +865,10 @@ fn test_ref_positive_partial_ord_f64() {
 }
 
 #[test]
-fn test_display_infinity() {
-    let p = Positive::INFINITY;
+fn test_display_max_is_finite() {
+    let p = Positive::MAX;
     let s = format!("{p}");
-    assert_eq!(s, format!("{}", f64::MAX));
+    assert_eq!(s, Decimal::MAX.to_string());
 }
 
 #[test]
@@ -876,10 +879,10 @@ fn test_display_integer() {
 }
 
 #[test]
-fn test_debug_infinity() {
-    let p = Positive::INFINITY;
+fn test_debug_max_is_finite() {
+    let p = Positive::MAX;
     let s = format!("{p:?}");
-    assert_eq!(s, format!("{}", f64::MAX));
+    assert_eq!(s, Decimal::MAX.to_string());
 }
 
 #[test]
@@ -890,11 +893,11 @@ fn test_debug_integer() {
 }
 
 #[test]
-fn test_serialize_infinity() {
-    let p = Positive::INFINITY;
+fn test_serialize_max_is_finite() {
+    let p = Positive::MAX;
     let json = serde_json::to_string(&p).unwrap();
-    // INFINITY serializes as f64::MAX in scientific notation
-    assert!(json.contains("1.7976931348623157e+308") || json.len() > 100);
+    // Too large for i64, so it falls back to the nearest f64
+    assert_eq!(json, "7.922816251426434e+28");
 }
 
 #[test]
@@ -904,6 +907,37 @@ fn test_deserialize_string_error() {
     assert!(result.is_err());
 }
 
+#[test]
+fn test_deserialize_numeric_string() {
+    let result: Positive = serde_json::from_str("\"12.50\"").unwrap();
+    assert_eq!(result, dec!(12.5));
+    assert_eq!(result.into_inner().scale(), 2);
+}
+
+#[test]
+fn test_deserialize_escaped_string() {
+    // Escapes force serde_json to hand over a transient `&str` instead of a borrowed one
+    let result: Positive = serde_json::from_str("\"\\u0031.5\"").unwrap();
+    assert_eq!(result, dec!(1.5));
+}
+
+#[test]
+fn test_deserialize_owned_string() {
+    let value = serde_json::Value::String("7.25".to_string());
+    let result: Positive = serde_json::from_value(value).unwrap();
+    assert_eq!(result, dec!(7.25));
+}
+
+#[test]
+fn test_deserialize_string_matches_from_str_error() {
+    for input in ["-1.5", "abc", ""] {
+        let json = format!("\"{input}\"");
+        let error = serde_json::from_str::<Positive>(&json).unwrap_err();
+        let expected = Positive::from_str(input).unwrap_err();
+        assert!(error.to_string().starts_with(&expected.to_string()));
+    }
+}
+
 #[test]
 fn test_deserialize_negative_i64() {
     let json = "-42";
@@ -919,11 +953,11 @@ fn test_deserialize_u64() {
 }
 
 #[test]
-fn test_deserialize_positive_infinity() {
-    // Test deserializing f64::INFINITY (represented as special value)
+fn test_deserialize_f64_max_is_out_of_range() {
+    // f64::MAX is no longer treated as an infinity sentinel
     let json = "1.7976931348623157e+308";
-    let result: Positive = serde_json::from_str(json).unwrap();
-    assert_eq!(result, Positive::INFINITY);
+    let result: Result<Positive, _> = serde_json::from_str(json);
+    assert!(result.is_err());
 }
 
 #[test]
@@ -1180,6 +1214,8 @@ fn test_try_from_i64() {
 }
 
 #[test]
+// `From` by default, `TryFrom` with `non-zero`.
+#[allow(clippy::unnecessary_fallible_conversions)]
 fn test_try_from_u64() {
     let p: Result<Positive, _> = 42u64.try_into();
     assert!(p.is_ok());
@@ -1272,14 +1308,43 @@ fn test_is_multiple_true_case() {
 
 #[test]
 fn test_is_multiple_near_boundary() {
-    let value = pos_or_panic!(9.999999999999998);
+    // Rounded to 15 significant digits, as `Positive::new` used to do.
+    let value = Positive::from_f64_with(
+        9.999999999999998,
+        positive::FromF64Mode::SignificantDigits(15),
+    )
+    .unwrap();
     assert!(value.is_multiple(1.0));
 }
 
+#[test]
+fn test_is_multiple_is_not_relative_to_the_value() {
+    assert!(!pos_or_panic!(1e16).is_multiple(3.0));
+    assert!(!pos_or_panic!(1e17).is_multiple(7.0));
+}
+
+#[test]
+fn test_near_boundary_keeps_round_trip_digits() {
+    // `Positive::new` keeps the shortest round-trip digits, so the value is
+    // no longer rounded to 10 and is not a whole number.
+    let value = pos_or_panic!(9.999999999999998);
+    assert_eq!(value.to_dec(), dec!(9.999999999999998));
+    assert!(!value.is_multiple(1.0));
+    assert!(!value.is_multiple_of(&pos_or_panic!(1.0)));
+
+    let rounded = Positive::from_f64_with(
+        9.999999999999998,
+        positive::FromF64Mode::SignificantDigits(15),
+    )
+    .unwrap();
+    assert_eq!(rounded.to_dec(), dec!(10));
+    assert!(rounded.is_multiple_of(&pos_or_panic!(1.0)));
+}
+
 #[test]
 fn test_is_multiple_with_non_finite() {
     // Test is_multiple when value would produce non-finite result
-    // Note: Positive::INFINITY is Decimal::MAX which is finite when converted to f64
+    // Note: Positive::MAX is Decimal::MAX, which is finite when converted to f64
     // The non-finite check is for edge cases in the modulo operation
     let value = pos_or_panic!(10.0);
     // Test normal case
@@ -1291,7 +1356,7 @@ fn test_is_multiple_with_non_finite() {
 fn test_display_large_integer_no_i64() {
     // Test Display when scale is 0 but value is too large for i64 (line 752)
     // Decimal::MAX has scale 0 but cannot fit in i64
-    let large = Positive::INFINITY;
+    let large = Positive::MAX;
     let s = format!("{large}");
     assert!(!s.is_empty());
 }
@@ -1299,7 +1364,7 @@ fn test_display_large_integer_no_i64() {
 #[test]
 fn test_debug_large_integer_no_i64() {
     // Test Debug when scale is 0 but value is too large for i64 (line 771)
-    let large = Positive::INFINITY;
+    let large = Positive::MAX;
     let s = format!("{large:?}");
     assert!(!s.is_empty());
 }
@@ -1407,3 +1472,1024 @@ fn test_decimal_add_ref_positive_impl() {
     let result = d + p;
     assert_eq!(result, dec!(8.0));
 }
+
+// ============================================================================
+// Inner field encapsulation
+// ============================================================================
+
+#[test]
+fn test_into_inner() {
+    let p = pos_or_panic!(42.5);
+    assert_eq!(p.into_inner(), dec!(42.5));
+}
+
+#[test]
+fn test_as_decimal() {
+    let p = pos_or_panic!(42.5);
+    assert_eq!(*p.as_decimal(), dec!(42.5));
+}
+
+#[test]
+#[allow(deprecated)]
+fn test_deprecated_inner_accessor() {
+    let p = pos_or_panic!(42.5);
+    assert_eq!(p.inner(), p.into_inner());
+}
+
+#[test]
+fn test_new_const() {
+    const TICK: Positive = Positive::new_const(dec!(0.25));
+    assert_eq!(TICK.into_inner(), dec!(0.25));
+}
+
+#[test]
+#[should_panic(expected = "positivity constraint")]
+fn test_new_const_rejects_negative() {
+    let _ = Positive::new_const(dec!(-1));
+}
+
+#[cfg(feature = "non-zero")]
+#[test]
+#[should_panic(expected = "positivity constraint")]
+fn test_new_const_rejects_zero_non_zero() {
+    let _ = Positive::new_const(Decimal::ZERO);
+}
+
+#[cfg(feature = "non-zero")]
+#[test]
+fn test_sub_or_none_rejects_zero_non_zero() {
+    let a = pos_or_panic!(5.0);
+    assert!(a.sub_or_none(&dec!(5.0)).is_none());
+}
+
+#[cfg(feature = "non-zero")]
+#[test]
+#[should_panic(expected = "Resulting value must be positive")]
+fn test_sub_to_zero_panics_non_zero() {
+    let a = pos_or_panic!(5.0);
+    let _ = a - a;
+}
+
+#[test]
+#[should_panic(expected = "Resulting value must be positive")]
+fn test_mul_negative_decimal_panics() {
+    let _ = pos_or_panic!(5.0) * dec!(-1);
+}
+
+#[test]
+fn test_round_to_nice_number_below_one() {
+    assert_eq!(pos_or_panic!(0.25).round_to_nice_number(), dec!(0.2));
+    assert_eq!(pos_or_panic!(0.08).round_to_nice_number(), dec!(0.1));
+}
+
+// ============================================================================
+// Operator validation matrix: negative and boundary operands
+// ============================================================================
+
+#[test]
+#[should_panic(expected = "Arithmetic error during addition")]
+fn test_add_negative_decimal_panics() {
+    let _ = pos_or_panic!(1.0) + dec!(-2);
+}
+
+#[allow(clippy::op_ref)]
+#[test]
+#[should_panic(expected = "Arithmetic error during addition")]
+fn test_add_negative_ref_decimal_panics() {
+    let _ = pos_or_panic!(1.0) + &dec!(-2);
+}
+
+#[test]
+fn test_add_negative_decimal_within_bounds() {
+    assert_eq!(pos_or_panic!(3.0) + dec!(-2), dec!(1));
+}
+
+#[test]
+#[should_panic(expected = "Arithmetic error during subtraction")]
+fn test_sub_decimal_below_zero_panics() {
+    let _ = pos_or_panic!(1.0) - dec!(2);
+}
+
+#[allow(clippy::op_ref)]
+#[test]
+#[should_panic(expected = "Arithmetic error during subtraction")]
+fn test_sub_ref_decimal_below_zero_panics() {
+    let _ = pos_or_panic!(1.0) - &dec!(2);
+}
+
+#[test]
+fn test_sub_negative_decimal_adds() {
+    assert_eq!(pos_or_panic!(1.0) - dec!(-2), dec!(3));
+}
+
+#[test]
+#[should_panic(expected = "Arithmetic error during multiplication")]
+fn test_mul_negative_decimal_matrix() {
+    let _ = pos_or_panic!(2.0) * dec!(-0.5);
+}
+
+#[test]
+#[should_panic(expected = "Arithmetic error during division")]
+fn test_div_negative_decimal_panics() {
+    let _ = pos_or_panic!(2.0) / dec!(-1);
+}
+
+#[allow(clippy::op_ref)]
+#[test]
+#[should_panic(expected = "Arithmetic error during division")]
+fn test_div_negative_ref_decimal_panics() {
+    let _ = pos_or_panic!(2.0) / &dec!(-1);
+}
+
+#[test]
+#[should_panic(expected = "Arithmetic error during division")]
+fn test_div_zero_decimal_panics() {
+    let _ = pos_or_panic!(2.0) / Decimal::ZERO;
+}
+
+#[test]
+#[should_panic(expected = "Arithmetic error during addition")]
+fn test_add_assign_negative_decimal_panics() {
+    let mut p = pos_or_panic!(1.0);
+    p += dec!(-2);
+}
+
+#[test]
+#[should_panic(expected = "Arithmetic error during multiplication")]
+fn test_mul_assign_negative_decimal_panics() {
+    let mut p = pos_or_panic!(1.0);
+    p *= dec!(-2);
+}
+
+#[test]
+#[should_panic(expected = "Arithmetic error during multiplication")]
+fn test_mul_negative_f64_panics() {
+    let _ = pos_or_panic!(2.0) * -1.0;
+}
+
+#[test]
+#[should_panic(expected = "Arithmetic error during division")]
+fn test_div_negative_f64_panics() {
+    let _ = pos_or_panic!(2.0) / -1.0;
+}
+
+#[allow(clippy::op_ref)]
+#[test]
+#[should_panic(expected = "Arithmetic error during division")]
+fn test_ref_div_negative_f64_panics() {
+    let _ = &pos_or_panic!(2.0) / -1.0;
+}
+
+#[test]
+#[should_panic(expected = "Arithmetic error during division")]
+fn test_div_zero_f64_panics() {
+    let _ = pos_or_panic!(2.0) / 0.0;
+}
+
+#[test]
+#[should_panic(expected = "Arithmetic error during subtraction")]
+fn test_sub_f64_below_zero_panics() {
+    let _ = pos_or_panic!(1.0) - 2.0;
+}
+
+#[test]
+#[should_panic(expected = "Arithmetic error during addition")]
+fn test_add_negative_f64_panics() {
+    let _ = pos_or_panic!(1.0) + -2.0;
+}
+
+#[test]
+#[should_panic(expected = "Arithmetic error during addition")]
+fn test_add_nan_f64_panics() {
+    let _ = pos_or_panic!(1.0) + f64::NAN;
+}
+
+#[cfg(not(feature = "non-zero"))]
+#[test]
+#[should_panic(expected = "Arithmetic error during division")]
+fn test_div_positive_by_zero_panics() {
+    let _ = pos_or_panic!(1.0) / Positive::ZERO;
+}
+
+#[cfg(not(feature = "non-zero"))]
+#[allow(clippy::op_ref)]
+#[test]
+#[should_panic(expected = "Arithmetic error during division")]
+fn test_ref_div_positive_by_zero_panics() {
+    let _ = &pos_or_panic!(1.0) / &Positive::ZERO;
+}
+
+#[test]
+#[should_panic(expected = "Arithmetic error during addition")]
+fn test_add_overflow_panics() {
+    let _ = Positive::MAX + Positive::ONE;
+}
+
+#[test]
+#[should_panic(expected = "Arithmetic error during multiplication")]
+fn test_mul_overflow_panics() {
+    let _ = Positive::MAX * Positive::TWO;
+}
+
+#[test]
+#[should_panic(expected = "Arithmetic error during powi")]
+fn test_powi_overflow_panics() {
+    let _ = Positive::MAX.powi(2);
+}
+
+#[test]
+#[should_panic(expected = "Arithmetic error during ln")]
+fn test_ln_below_one_panics() {
+    let _ = pos_or_panic!(0.5).ln();
+}
+
+#[test]
+#[should_panic(expected = "Arithmetic error during log10")]
+fn test_log10_below_one_panics() {
+    let _ = pos_or_panic!(0.5).log10();
+}
+
+#[cfg(not(feature = "non-zero"))]
+#[test]
+#[should_panic(expected = "Arithmetic error during ln")]
+fn test_ln_zero_panics() {
+    let _ = Positive::ZERO.ln();
+}
+
+#[cfg(not(feature = "non-zero"))]
+#[test]
+fn test_ln_log10_of_one_is_zero() {
+    assert_eq!(Positive::ONE.ln(), Positive::ZERO);
+    assert_eq!(Positive::ONE.log10(), Positive::ZERO);
+}
+
+#[cfg(feature = "non-zero")]
+#[test]
+#[should_panic(expected = "Arithmetic error during ln")]
+fn test_ln_of_one_panics_non_zero() {
+    let _ = Positive::ONE.ln();
+}
+
+#[cfg(not(feature = "non-zero"))]
+#[test]
+fn test_operators_reaching_zero_boundary() {
+    let p = pos_or_panic!(2.0);
+    assert_eq!(p - p, Positive::ZERO);
+    assert_eq!(p - dec!(2), Positive::ZERO);
+    assert_eq!(p + dec!(-2), Positive::ZERO);
+    assert_eq!(p * Decimal::ZERO, Positive::ZERO);
+    assert_eq!(p - 2.0, Positive::ZERO);
+    assert_eq!(p * 0.0, Positive::ZERO);
+}
+
+#[cfg(feature = "non-zero")]
+#[test]
+#[should_panic(expected = "Arithmetic error during multiplication")]
+fn test_mul_by_zero_decimal_panics_non_zero() {
+    let _ = pos_or_panic!(2.0) * Decimal::ZERO;
+}
+
+#[cfg(feature = "non-zero")]
+#[test]
+#[should_panic(expected = "Arithmetic error during floor")]
+fn test_floor_to_zero_panics_non_zero() {
+    let _ = pos_or_panic!(0.5).floor();
+}
+
+// ============================================================================
+// Checked arithmetic family
+// ============================================================================
+
+fn assert_arithmetic_error(result: positive::PositiveResult<Positive>, operation: &str) {
+    match result {
+        Err(positive::PositiveError::ArithmeticError { operation: op, .. }) => {
+            assert_eq!(op, operation)
+        }
+        other => panic!("expected ArithmeticError for {operation}, got {other:?}"),
+    }
+}
+
+#[test]
+fn test_checked_add() {
+    let a = pos_or_panic!(2.5);
+    assert_eq!(a.checked_add(&Positive::TWO).unwrap(), dec!(4.5));
+    assert_arithmetic_error(Positive::MAX.checked_add(&Positive::ONE), "addition");
+}
+
+#[test]
+fn test_checked_sub_error_shape() {
+    assert_arithmetic_error(Positive::ONE.checked_sub(&Positive::TWO), "subtraction");
+}
+
+#[test]
+fn test_checked_mul() {
+    let a = pos_or_panic!(2.5);
+    assert_eq!(a.checked_mul(&Positive::TWO).unwrap(), dec!(5));
+    assert_arithmetic_error(Positive::MAX.checked_mul(&Positive::TWO), "multiplication");
+}
+
+#[test]
+fn test_checked_div_overflow() {
+    assert_arithmetic_error(Positive::MAX.checked_div(&pos_or_panic!(0.1)), "division");
+}
+
+#[cfg(not(feature = "non-zero"))]
+#[test]
+fn test_checked_div_by_zero_error_shape() {
+    assert_arithmetic_error(Positive::ONE.checked_div(&Positive::ZERO), "division");
+}
+
+#[test]
+fn test_checked_rem() {
+    let a = pos_or_panic!(10.5);
+    assert_eq!(a.checked_rem(&Positive::FOUR).unwrap(), dec!(2.5));
+}
+
+#[cfg(not(feature = "non-zero"))]
+#[test]
+fn test_checked_rem_by_zero() {
+    assert_arithmetic_error(Positive::TEN.checked_rem(&Positive::ZERO), "remainder");
+    assert_eq!(
+        Positive::TEN.checked_rem(&Positive::FIVE).unwrap(),
+        Positive::ZERO
+    );
+}
+
+#[cfg(feature = "non-zero")]
+#[test]
+fn test_checked_rem_zero_result_non_zero() {
+    assert_arithmetic_error(Positive::TEN.checked_rem(&Positive::FIVE), "remainder");
+}
+
+#[test]
+fn test_checked_powers() {
+    let two = Positive::TWO;
+    assert_eq!(two.checked_powi(3).unwrap(), dec!(8));
+    assert_eq!(two.checked_powi(-1).unwrap(), dec!(0.5));
+    assert_eq!(two.checked_powu(10).unwrap(), dec!(1024));
+    assert_eq!(two.checked_pow(Positive::THREE).unwrap(), dec!(8));
+    assert_eq!(two.checked_powd(dec!(2)).unwrap(), dec!(4));
+
+    assert_arithmetic_error(Positive::MAX.checked_powi(2), "powi");
+    assert_arithmetic_error(Positive::MAX.checked_powu(2), "powu");
+    assert_arithmetic_error(Positive::MAX.checked_pow(Positive::TWO), "pow");
+    assert_arithmetic_error(Positive::MAX.checked_powd(dec!(2)), "powd");
+}
+
+#[cfg(not(feature = "non-zero"))]
+#[test]
+fn test_checked_powers_of_zero_base() {
+    assert_arithmetic_error(Positive::ZERO.checked_powi(-1), "powi");
+    let error = Positive::ZERO.checked_powi(-1).unwrap_err();
+    assert_eq!(error.kind(), positive::PositiveErrorKind::DivisionByZero);
+    assert_eq!(error.code(), "arithmetic.division_by_zero");
+    assert_eq!(Positive::ZERO.checked_powi(0).unwrap(), Positive::ONE);
+    let error = Positive::ZERO.checked_powd(dec!(-1)).unwrap_err();
+    assert_eq!(error.kind(), positive::PositiveErrorKind::DivisionByZero);
+    assert_arithmetic_error(Positive::ZERO.checked_powd(dec!(-0.5)), "powd");
+    assert!(Positive::ZERO.checked_powd(dec!(2)).unwrap().is_zero());
+}
+
+#[test]
+fn test_checked_exp() {
+    let result = Positive::ONE.checked_exp().unwrap();
+    assert!((result.to_f64() - std::f64::consts::E).abs() < 1e-9);
+    assert_arithmetic_error(Positive::HUNDRED.checked_exp(), "exp");
+}
+
+#[test]
+fn test_checked_ln_and_log10() {
+    assert_eq!(Positive::HUNDRED.checked_log10().unwrap(), dec!(2));
+    let ln_e = pos_or_panic!(std::f64::consts::E).checked_ln().unwrap();
+    assert!((ln_e.to_f64() - 1.0).abs() < 1e-9);
+
+    assert_arithmetic_error(pos_or_panic!(0.5).checked_ln(), "ln");
+    assert_arithmetic_error(pos_or_panic!(0.5).checked_log10(), "log10");
+}
+
+#[cfg(not(feature = "non-zero"))]
+#[test]
+fn test_checked_ln_of_zero() {
+    assert_arithmetic_error(Positive::ZERO.checked_ln(), "ln");
+    assert_arithmetic_error(Positive::ZERO.checked_log10(), "log10");
+}
+
+// ============================================================================
+// Saturating arithmetic family
+// ============================================================================
+
+#[test]
+fn test_min_max_constants() {
+    assert_eq!(Positive::MAX.into_inner(), Decimal::MAX);
+    assert!(Positive::MIN <= Positive::ONE);
+    assert!(Positive::new_decimal(Positive::MIN.into_inner()).is_ok());
+}
+
+#[cfg(not(feature = "non-zero"))]
+#[test]
+fn test_min_is_zero() {
+    assert_eq!(Positive::MIN, Positive::ZERO);
+}
+
+#[cfg(feature = "non-zero")]
+#[test]
+fn test_min_is_smallest_positive_non_zero() {
+    assert_eq!(Positive::MIN.into_inner(), Decimal::new(1, 28));
+}
+
+#[test]
+fn test_saturating_add() {
+    assert_eq!(
+        Positive::ONE.saturating_add(&Positive::TWO),
+        Positive::THREE
+    );
+    assert_eq!(Positive::MAX.saturating_add(&Positive::ONE), Positive::MAX);
+}
+
+#[test]
+fn test_saturating_sub_both_features() {
+    assert_eq!(
+        Positive::FIVE.saturating_sub(&Positive::TWO),
+        Positive::THREE
+    );
+    assert_eq!(Positive::TWO.saturating_sub(&Positive::FIVE), Positive::MIN);
+    assert_eq!(Positive::TWO.saturating_sub(&Positive::TWO), Positive::MIN);
+}
+
+#[test]
+fn test_sub_or_zero_both_features() {
+    assert_eq!(Positive::FIVE.sub_or_zero(&dec!(2)), Positive::THREE);
+    assert_eq!(Positive::FIVE.sub_or_zero(&dec!(10)), Positive::MIN);
+    assert_eq!(Positive::MAX.sub_or_zero(&dec!(-1)), Positive::MAX);
+}
+
+#[test]
+fn test_saturating_mul() {
+    assert_eq!(
+        Positive::TWO.saturating_mul(&Positive::THREE),
+        Positive::SIX
+    );
+    assert_eq!(Positive::MAX.saturating_mul(&Positive::TWO), Positive::MAX);
+    let tiny = Positive::new_decimal(Decimal::new(1, 28)).unwrap();
+    assert_eq!(tiny.saturating_mul(&tiny), Positive::MIN);
+}
+
+#[test]
+fn test_saturating_pow_family() {
+    assert_eq!(
+        Positive::TWO.saturating_pow(Positive::THREE),
+        Positive::EIGHT
+    );
+    assert_eq!(Positive::TWO.saturating_powi(3), Positive::EIGHT);
+    assert_eq!(Positive::TWO.saturating_powu(3), Positive::EIGHT);
+
+    assert_eq!(Positive::MAX.saturating_pow(Positive::TWO), Positive::MAX);
+    assert_eq!(Positive::MAX.saturating_powi(2), Positive::MAX);
+    assert_eq!(Positive::MAX.saturating_powu(2), Positive::MAX);
+    assert_eq!(Positive::TEN.saturating_powi(-40), Positive::MIN);
+}
+
+#[test]
+fn test_saturating_pow_underflow() {
+    let tenth = pos_or_panic!(0.1);
+    assert_eq!(tenth.saturating_powu(40), Positive::MIN);
+    assert_eq!(
+        tenth.saturating_pow(Positive::new_decimal(dec!(40.5)).unwrap()),
+        Positive::MIN
+    );
+    assert_eq!(tenth.saturating_powi(-40), Positive::MAX);
+}
+
+// ============================================================================
+// Compound assignment operators
+// ============================================================================
+
+#[test]
+fn test_add_assign_matrix() {
+    let mut p = pos_or_panic!(1.0);
+    p += Positive::ONE;
+    p += &Positive::ONE;
+    p += dec!(1);
+    p += &dec!(1);
+    p += 1.0;
+    assert_eq!(p, dec!(6));
+}
+
+#[test]
+fn test_sub_assign_matrix() {
+    let mut p = pos_or_panic!(10.0);
+    p -= Positive::ONE;
+    p -= &Positive::ONE;
+    p -= dec!(1);
+    p -= &dec!(1);
+    p -= 1.5;
+    assert_eq!(p, dec!(4.5));
+}
+
+#[test]
+fn test_mul_assign_matrix() {
+    let mut p = pos_or_panic!(1.0);
+    p *= Positive::TWO;
+    p *= &Positive::TWO;
+    p *= dec!(2);
+    p *= &dec!(2);
+    p *= 0.5;
+    assert_eq!(p, dec!(8));
+}
+
+#[test]
+fn test_div_assign_matrix() {
+    let mut p = pos_or_panic!(64.0);
+    p /= Positive::TWO;
+    p /= &Positive::TWO;
+    p /= dec!(2);
+    p /= &dec!(2);
+    p /= 0.5;
+    assert_eq!(p, dec!(8));
+}
+
+#[test]
+fn test_rem_assign_matrix() {
+    let mut p = pos_or_panic!(100.0);
+    p %= pos_or_panic!(30.0);
+    assert_eq!(p, dec!(10));
+    p %= &pos_or_panic!(6.0);
+    assert_eq!(p, dec!(4));
+    p %= dec!(3);
+    assert_eq!(p, dec!(1));
+    let mut q = pos_or_panic!(7.5);
+    q %= &dec!(2);
+    assert_eq!(q, dec!(1.5));
+    q %= 1.0;
+    assert_eq!(q, dec!(0.5));
+}
+
+#[test]
+#[should_panic(expected = "Arithmetic error during subtraction")]
+fn test_sub_assign_below_zero_panics() {
+    let mut p = pos_or_panic!(1.0);
+    p -= Positive::TWO;
+}
+
+#[test]
+#[should_panic(expected = "Arithmetic error during subtraction")]
+fn test_sub_assign_f64_below_zero_panics() {
+    let mut p = pos_or_panic!(1.0);
+    p -= 2.0;
+}
+
+#[test]
+#[should_panic(expected = "Arithmetic error during multiplication")]
+fn test_mul_assign_negative_f64_panics() {
+    let mut p = pos_or_panic!(1.0);
+    p *= -2.0;
+}
+
+#[test]
+#[should_panic(expected = "Arithmetic error during division")]
+fn test_div_assign_by_zero_panics() {
+    let mut p = pos_or_panic!(1.0);
+    p /= Decimal::ZERO;
+}
+
+#[test]
+#[should_panic(expected = "Arithmetic error during division")]
+fn test_div_assign_negative_ref_decimal_panics() {
+    let mut p = pos_or_panic!(1.0);
+    p /= &dec!(-1);
+}
+
+#[test]
+#[should_panic(expected = "Arithmetic error during remainder")]
+fn test_rem_assign_by_zero_panics() {
+    let mut p = pos_or_panic!(1.0);
+    p %= 0.0;
+}
+
+#[test]
+#[should_panic(expected = "Arithmetic error during addition")]
+fn test_add_assign_nan_panics() {
+    let mut p = pos_or_panic!(1.0);
+    p += f64::NAN;
+}
+
+#[test]
+#[should_panic(expected = "Arithmetic error during addition")]
+fn test_add_assign_overflow_panics() {
+    let mut p = Positive::MAX;
+    p += &Positive::ONE;
+}
+
+#[cfg(feature = "non-zero")]
+#[test]
+#[should_panic(expected = "Arithmetic error during remainder")]
+fn test_rem_assign_to_zero_panics_non_zero() {
+    let mut p = pos_or_panic!(10.0);
+    p %= Positive::FIVE;
+}
+
+// ============================================================================
+// Remainder and Euclidean division
+// ============================================================================
+
+#[test]
+fn test_rem_operator() {
+    assert_eq!(pos_or_panic!(1250.0) % pos_or_panic!(100.0), dec!(50));
+    assert_eq!(pos_or_panic!(7.5) % dec!(2), dec!(1.5));
+}
+
+#[test]
+fn test_div_euclid() {
+    let lot = pos_or_panic!(100.0);
+    assert_eq!(pos_or_panic!(1250.0).div_euclid(&lot).unwrap(), dec!(12));
+    assert_eq!(pos_or_panic!(1200.0).div_euclid(&lot).unwrap(), dec!(12));
+    assert_eq!(
+        pos_or_panic!(0.75).div_euclid(&pos_or_panic!(0.2)).unwrap(),
+        dec!(3)
+    );
+}
+
+#[test]
+fn test_rem_euclid() {
+    let lot = pos_or_panic!(100.0);
+    assert_eq!(pos_or_panic!(1250.0).rem_euclid(&lot).unwrap(), dec!(50));
+    assert_eq!(
+        pos_or_panic!(0.75).rem_euclid(&pos_or_panic!(0.2)).unwrap(),
+        dec!(0.15)
+    );
+}
+
+#[test]
+fn test_div_rem_recombines() {
+    let value = pos_or_panic!(123.45);
+    let step = pos_or_panic!(0.7);
+    let (quotient, remainder) = value.div_rem(&step).unwrap();
+    assert_eq!(quotient, dec!(176));
+    assert_eq!(quotient * step + remainder, value);
+}
+
+#[cfg(not(feature = "non-zero"))]
+#[test]
+fn test_euclid_zero_divisor_errors() {
+    let value = pos_or_panic!(10.0);
+    assert_arithmetic_error(value.div_euclid(&Positive::ZERO), "div_euclid");
+    assert_arithmetic_error(value.rem_euclid(&Positive::ZERO), "rem_euclid");
+    assert!(matches!(
+        value.div_rem(&Positive::ZERO),
+        Err(positive::PositiveError::ArithmeticError { ref operation, ref reason, .. })
+            if operation == "div_rem" && reason == "division by zero"
+    ));
+}
+
+#[cfg(not(feature = "non-zero"))]
+#[test]
+fn test_euclid_zero_parts() {
+    let (quotient, remainder) = pos_or_panic!(50.0).div_rem(&pos_or_panic!(100.0)).unwrap();
+    assert_eq!(quotient, Positive::ZERO);
+    assert_eq!(remainder, dec!(50));
+    assert_eq!(
+        pos_or_panic!(200.0)
+            .rem_euclid(&pos_or_panic!(100.0))
+            .unwrap(),
+        Positive::ZERO
+    );
+}
+
+#[cfg(feature = "non-zero")]
+#[test]
+fn test_euclid_zero_parts_non_zero() {
+    assert_arithmetic_error(
+        pos_or_panic!(50.0).div_euclid(&pos_or_panic!(100.0)),
+        "div_euclid",
+    );
+    assert_arithmetic_error(
+        pos_or_panic!(200.0).rem_euclid(&pos_or_panic!(100.0)),
+        "rem_euclid",
+    );
+}
+
+// ============================================================================
+// Structured error kinds
+// ============================================================================
+
+#[test]
+fn test_error_kind_new_decimal_negative() {
+    let value = Decimal::new(-125, 2);
+    let error = Positive::new_decimal(value).unwrap_err();
+    assert_eq!(error.kind(), positive::PositiveErrorKind::Negative);
+    assert_eq!(error.value(), Some(value));
+    assert_eq!(error.code(), "out_of_bounds.negative");
+}
+
+#[test]
+fn test_error_kind_new_non_finite() {
+    let error = Positive::new(f64::NAN).unwrap_err();
+    assert_eq!(error.kind(), positive::PositiveErrorKind::NaN);
+    let error = Positive::new(f64::INFINITY).unwrap_err();
+    assert_eq!(error.kind(), positive::PositiveErrorKind::Infinite);
+}
+
+#[test]
+fn test_error_kind_arithmetic() {
+    let a = pos_or_panic!(2.0);
+    let b = pos_or_panic!(3.0);
+    let error = a.checked_sub(&b).unwrap_err();
+    assert_eq!(error.kind(), positive::PositiveErrorKind::Negative);
+    assert_eq!(error.value(), Some(Decimal::NEGATIVE_ONE));
+    assert_eq!(error.code(), "arithmetic.negative");
+
+    let error = Positive::MAX.checked_add(&b).unwrap_err();
+    assert_eq!(error.kind(), positive::PositiveErrorKind::Overflow);
+    assert_eq!(error.value(), None);
+}
+
+#[test]
+#[cfg(not(feature = "non-zero"))]
+fn test_error_kind_division_by_zero() {
+    let a = pos_or_panic!(2.0);
+    for error in [
+        a.checked_div(&Positive::ZERO).unwrap_err(),
+        a.checked_rem(&Positive::ZERO).unwrap_err(),
+        a.div_rem(&Positive::ZERO).unwrap_err(),
+    ] {
+        assert_eq!(error.kind(), positive::PositiveErrorKind::DivisionByZero);
+        assert_eq!(error.code(), "arithmetic.division_by_zero");
+    }
+    assert_eq!(
+        Positive::ZERO.checked_ln().unwrap_err().kind(),
+        positive::PositiveErrorKind::Infinite
+    );
+}
+
+#[test]
+fn test_error_kind_parse() {
+    let error = Positive::from_str("1.2.3").unwrap_err();
+    assert_eq!(error.kind(), positive::PositiveErrorKind::Parse);
+    assert_eq!(error.code(), "parse");
+}
+
+// ============================================================================
+// f64 classification and exact construction
+// ============================================================================
+
+#[test]
+fn test_new_classifies_f64_failures() {
+    use positive::PositiveErrorKind;
+
+    let kind = |value: f64| Positive::new(value).unwrap_err().kind();
+    assert_eq!(kind(f64::NAN), PositiveErrorKind::NaN);
+    assert_eq!(kind(f64::INFINITY), PositiveErrorKind::Infinite);
+    assert_eq!(kind(f64::NEG_INFINITY), PositiveErrorKind::Infinite);
+    assert_eq!(kind(1e29), PositiveErrorKind::Overflow);
+    assert_eq!(kind(f64::MAX), PositiveErrorKind::Overflow);
+    assert_eq!(kind(-1.5), PositiveErrorKind::Negative);
+    assert_eq!(kind(-1e30), PositiveErrorKind::Negative);
+    assert_eq!(kind(1e-29), PositiveErrorKind::PrecisionLoss);
+    assert_eq!(
+        kind(f64::MIN_POSITIVE / 2.0),
+        PositiveErrorKind::PrecisionLoss
+    );
+    assert_eq!(kind(5e-324), PositiveErrorKind::PrecisionLoss);
+}
+
+#[test]
+fn test_new_negative_keeps_value() {
+    let error = Positive::new(-2.5).unwrap_err();
+    assert!(matches!(error, positive::PositiveError::OutOfBounds { .. }));
+    assert_eq!(error.value(), Some(Decimal::new(-25, 1)));
+}
+
+#[test]
+#[cfg(not(feature = "non-zero"))]
+fn test_new_negative_zero_is_zero() {
+    let zero = Positive::new(-0.0).unwrap();
+    assert!(zero.is_zero());
+    assert!(!zero.to_dec().is_sign_negative());
+    assert_eq!(zero.to_string(), "0");
+    assert_eq!(Positive::new_exact(-0.0).unwrap().to_string(), "0");
+}
+
+#[test]
+#[cfg(feature = "non-zero")]
+fn test_new_negative_zero_is_zero() {
+    use positive::PositiveErrorKind;
+
+    assert_eq!(
+        Positive::new(-0.0).unwrap_err().kind(),
+        PositiveErrorKind::Zero
+    );
+    assert_eq!(
+        Positive::new_exact(-0.0).unwrap_err().kind(),
+        PositiveErrorKind::Zero
+    );
+}
+
+#[test]
+fn test_new_smallest_decimal() {
+    assert_eq!(Positive::new(1e-28).unwrap().to_dec(), Decimal::new(1, 28));
+}
+
+#[test]
+fn test_new_exact_keeps_round_trip_digits() {
+    assert_eq!(
+        Positive::new(0.1 + 0.2).unwrap().to_string(),
+        "0.30000000000000004"
+    );
+    let exact = Positive::new_exact(0.1 + 0.2).unwrap();
+    assert_eq!(
+        exact.to_dec(),
+        Decimal::from_str("0.30000000000000004").unwrap()
+    );
+    assert_eq!(exact.to_f64(), 0.1 + 0.2);
+    assert_eq!(
+        Positive::new_exact(0.1).unwrap().to_dec(),
+        Decimal::new(1, 1)
+    );
+    assert_eq!(
+        Positive::new_exact(1e28).unwrap().to_dec(),
+        Decimal::from_str("10000000000000000000000000000").unwrap()
+    );
+}
+
+#[test]
+fn test_new_exact_rejects_precision_loss() {
+    use positive::PositiveErrorKind;
+
+    let kind = |value: f64| Positive::new_exact(value).unwrap_err().kind();
+    assert_eq!(
+        kind(1.2345678901234567e-20),
+        PositiveErrorKind::PrecisionLoss
+    );
+    assert_eq!(kind(1e-29), PositiveErrorKind::PrecisionLoss);
+    assert_eq!(kind(5e-324), PositiveErrorKind::PrecisionLoss);
+    assert_eq!(kind(1e29), PositiveErrorKind::Overflow);
+    assert_eq!(kind(f64::NAN), PositiveErrorKind::NaN);
+    assert_eq!(kind(-0.5), PositiveErrorKind::Negative);
+}
+
+// ============================================================================
+// f64 conversion modes
+// ============================================================================
+
+#[test]
+fn test_from_f64_with_modes() {
+    use positive::FromF64Mode;
+
+    let convert = |value: f64, mode| Positive::from_f64_with(value, mode).unwrap().to_dec();
+    assert_eq!(
+        convert(1.1 * 1.1, FromF64Mode::ShortestRoundTrip),
+        Decimal::from_str("1.2100000000000002").unwrap()
+    );
+    assert_eq!(
+        convert(0.1, FromF64Mode::FullExpansion),
+        Decimal::from_str("0.1000000000000000055511151231").unwrap()
+    );
+    assert_eq!(
+        convert(1234.5678, FromF64Mode::SignificantDigits(2)),
+        Decimal::from(1200)
+    );
+    assert_eq!(
+        convert(2.675, FromF64Mode::DecimalPlaces(2)),
+        Decimal::from_str("2.68").unwrap()
+    );
+    assert_eq!(
+        convert(0.125, FromF64Mode::DecimalPlaces(2)),
+        Decimal::from_str("0.12").unwrap()
+    );
+}
+
+#[test]
+fn test_from_f64_with_default_matches_new() {
+    for value in [0.1, 0.1 + 0.2, 1.0 / 3.0, 123.456, 1e20] {
+        assert_eq!(
+            Positive::from_f64_with(value, positive::FromF64Mode::default()).unwrap(),
+            Positive::new(value).unwrap()
+        );
+        assert_eq!(
+            Positive::try_from(value).unwrap(),
+            Positive::new(value).unwrap()
+        );
+        assert_eq!(pos!(value).unwrap(), Positive::new(value).unwrap());
+    }
+}
+
+#[test]
+fn test_from_f64_with_zero_significant_digits() {
+    assert!(matches!(
+        Positive::from_f64_with(1.5, positive::FromF64Mode::SignificantDigits(0)),
+        Err(positive::PositiveError::InvalidPrecision { .. })
+    ));
+}
+
+#[test]
+#[cfg(not(feature = "non-zero"))]
+fn test_from_f64_with_rounding_to_zero() {
+    let rounded = Positive::from_f64_with(0.001, positive::FromF64Mode::DecimalPlaces(2));
+    assert!(rounded.unwrap().is_zero());
+}
+
+#[test]
+fn test_f64_operators_keep_round_trip_digits() {
+    let value = pos_or_panic!(1.0);
+    assert_eq!(
+        (value + (0.1 + 0.2)).to_dec(),
+        Decimal::from_str("1.30000000000000004").unwrap()
+    );
+    let mut assigned = value;
+    assigned *= 0.1 + 0.2;
+    assert_eq!(
+        assigned.to_dec(),
+        Decimal::from_str("0.30000000000000004").unwrap()
+    );
+}
+
+#[test]
+fn test_macros_accept_mode() {
+    use positive::FromF64Mode;
+
+    let mode = FromF64Mode::SignificantDigits(3);
+    assert_eq!(pos!(123.456, mode).unwrap().to_dec(), Decimal::from(123));
+    assert_eq!(pos_or_panic!(123.456, mode).to_dec(), Decimal::from(123));
+    assert_eq!(spos!(123.456, mode).unwrap().to_dec(), Decimal::from(123));
+    assert!(spos!(-1.0, mode).is_none());
+}
+
+#[test]
+fn test_from_f64_with_more_significant_digits_than_value() {
+    let mode = positive::FromF64Mode::SignificantDigits(28);
+    assert_eq!(
+        Positive::from_f64_with(0.000123, mode).unwrap().to_dec(),
+        dec!(0.000123)
+    );
+}
+
+// ============================================================================
+// Rounding strategies
+// ============================================================================
+
+#[test]
+fn test_round_dp_with_strategy() {
+    use positive::RoundingStrategy;
+
+    let value = pos_or_panic!(2.345);
+    assert_eq!(
+        value.round_dp_with_strategy(2, RoundingStrategy::MidpointNearestEven),
+        dec!(2.34)
+    );
+    assert_eq!(
+        value.round_dp_with_strategy(2, RoundingStrategy::MidpointAwayFromZero),
+        dec!(2.35)
+    );
+    assert_eq!(
+        value.round_dp_with_strategy(1, RoundingStrategy::ToPositiveInfinity),
+        dec!(2.4)
+    );
+    assert_eq!(
+        value.round_dp_with_strategy(5, RoundingStrategy::ToZero),
+        dec!(2.345)
+    );
+}
+
+#[test]
+fn test_round_sf() {
+    assert_eq!(pos_or_panic!(123456.0).round_sf(3), dec!(123000));
+    assert_eq!(pos_or_panic!(0.0012345).round_sf(2), dec!(0.0012));
+    assert_eq!(pos_or_panic!(2.5).round_sf(1), dec!(2));
+    assert_eq!(pos_or_panic!(9.96).round_sf(2), dec!(10));
+    assert_eq!(pos_or_panic!(7.0).round_sf(0), dec!(7));
+    assert_eq!(pos_or_panic!(0.000123).round_sf(28), dec!(0.000123));
+    assert_eq!(Positive::MAX.round_sf(29), Positive::MAX);
+}
+
+#[test]
+#[should_panic(expected = "Arithmetic error during round_sf")]
+fn test_round_sf_overflow_panics() {
+    let _ = Positive::MAX.round_sf(1);
+}
+
+#[test]
+fn test_floor_ceil_truncate_to() {
+    let value = pos_or_panic!(1.2389);
+    assert_eq!(value.floor_to(2), dec!(1.23));
+    assert_eq!(value.ceil_to(2), dec!(1.24));
+    assert_eq!(value.truncate_to(2), dec!(1.23));
+    assert_eq!(value.floor_to(0), dec!(1));
+    assert_eq!(value.ceil_to(0), dec!(2));
+    assert_eq!(value.ceil_to(6), value);
+    assert_eq!(pos_or_panic!(1.2).ceil_to(2), dec!(1.2));
+}
+
+#[test]
+#[cfg(not(feature = "non-zero"))]
+fn test_floor_to_reaching_zero() {
+    assert!(pos_or_panic!(0.004).floor_to(2).is_zero());
+    assert!(pos_or_panic!(0.004).truncate_to(2).is_zero());
+    assert_eq!(pos_or_panic!(0.004).ceil_to(2), dec!(0.01));
+}
+
+#[cfg(feature = "non-zero")]
+#[test]
+#[should_panic(expected = "Arithmetic error during floor_to")]
+fn test_floor_to_zero_panics_non_zero_places() {
+    let _ = pos_or_panic!(0.004).floor_to(2);
+}
@@ -0,0 +1,380 @@
+/******************************************************************************
+   Author: Joaquín Béjar García
+   Email: jb@taunais.com
+   Date: 18/10/26
+******************************************************************************/
+
+use positive::Positive;
+use rust_decimal::Decimal;
+use rust_decimal_macros::dec;
+use serde::{Deserialize, Serialize};
+
+// ============================================================================
+// as_str
+// ============================================================================
+
+#[derive(Debug, Serialize, Deserialize)]
+struct Exact {
+    #[serde(with = "positive::serde::as_str")]
+    value: Positive,
+}
+
+#[test]
+fn test_as_str_keeps_scale() {
+    let record = Exact {
+        value: Positive::new_decimal(dec!(123.4500)).unwrap(),
+    };
+    let json = serde_json::to_string(&record).unwrap();
+    assert_eq!(json, r#"{"value":"123.4500"}"#);
+}
+
+#[test]
+fn test_as_str_roundtrip_is_bit_identical() {
+    for value in [
+        dec!(0.1),
+        dec!(123.4500),
+        dec!(1),
+        dec!(0.0000000000000000000000000001),
+        Decimal::MAX,
+    ] {
+        let record = Exact {
+            value: Positive::new_decimal(value).unwrap(),
+        };
+        let json = serde_json::to_string(&record).unwrap();
+        let parsed: Exact = serde_json::from_str(&json).unwrap();
+        assert_eq!(parsed.value.into_inner().serialize(), value.serialize());
+    }
+}
+
+#[cfg(not(feature = "non-zero"))]
+#[test]
+fn test_as_str_zero() {
+    let parsed: Exact = serde_json::from_str(r#"{"value":"0.00"}"#).unwrap();
+    assert_eq!(parsed.value.into_inner().scale(), 2);
+    assert!(parsed.value.is_zero());
+}
+
+#[test]
+fn test_as_str_rejects_negative() {
+    assert!(serde_json::from_str::<Exact>(r#"{"value":"-1.5"}"#).is_err());
+}
+
+#[test]
+fn test_as_str_rejects_garbage() {
+    assert!(serde_json::from_str::<Exact>(r#"{"value":"12abc"}"#).is_err());
+}
+
+#[test]
+fn test_as_str_rejects_inexact_input() {
+    // A scale of 29 exceeds what `Decimal` can store without rounding
+    let json = r#"{"value":"1.23456789012345678901234567891"}"#;
+    assert!(serde_json::from_str::<Exact>(json).is_err());
+}
+
+#[test]
+fn test_as_str_rejects_numbers() {
+    assert!(serde_json::from_str::<Exact>(r#"{"value":1.5}"#).is_err());
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct ExactCollections {
+    #[serde(with = "positive::serde::as_str_option")]
+    maybe: Option<Positive>,
+    #[serde(with = "positive::serde::as_str_vec")]
+    many: Vec<Positive>,
+}
+
+#[test]
+fn test_as_str_collections() {
+    let record = ExactCollections {
+        maybe: Some(Positive::new_decimal(dec!(1.50)).unwrap()),
+        many: vec![
+            Positive::new_decimal(dec!(2.0)).unwrap(),
+            Positive::new_decimal(dec!(3.125)).unwrap(),
+        ],
+    };
+    let json = serde_json::to_string(&record).unwrap();
+    assert_eq!(json, r#"{"maybe":"1.50","many":["2.0","3.125"]}"#);
+    assert_eq!(
+        serde_json::from_str::<ExactCollections>(&json).unwrap(),
+        record
+    );
+
+    let parsed: ExactCollections = serde_json::from_str(r#"{"maybe":null,"many":[]}"#).unwrap();
+    assert_eq!(parsed.maybe, None);
+    assert!(parsed.many.is_empty());
+}
+
+// ============================================================================
+// as_f64
+// ============================================================================
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct Float {
+    #[serde(with = "positive::serde::as_f64")]
+    value: Positive,
+    #[serde(with = "positive::serde::as_f64_option")]
+    maybe: Option<Positive>,
+    #[serde(with = "positive::serde::as_f64_vec")]
+    many: Vec<Positive>,
+}
+
+#[test]
+fn test_as_f64_always_writes_floats() {
+    let record = Float {
+        value: Positive::new_decimal(dec!(5)).unwrap(),
+        maybe: Some(Positive::new_decimal(dec!(0.25)).unwrap()),
+        many: vec![
+            Positive::new_decimal(dec!(1)).unwrap(),
+            Positive::new_decimal(dec!(2.5)).unwrap(),
+        ],
+    };
+    let json = serde_json::to_string(&record).unwrap();
+    assert_eq!(json, r#"{"value":5.0,"maybe":0.25,"many":[1.0,2.5]}"#);
+    assert_eq!(serde_json::from_str::<Float>(&json).unwrap(), record);
+}
+
+#[test]
+fn test_as_f64_accepts_integers_and_strings() {
+    let parsed: Float = serde_json::from_str(r#"{"value":"7.5","maybe":null,"many":[3]}"#).unwrap();
+    assert_eq!(parsed.value, dec!(7.5));
+    assert_eq!(parsed.maybe, None);
+    assert_eq!(parsed.many, vec![Positive::new_decimal(dec!(3)).unwrap()]);
+}
+
+// ============================================================================
+// as_minor_units
+// ============================================================================
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct Cents {
+    #[serde(with = "positive::serde::as_minor_units::<2>")]
+    value: Positive,
+    #[serde(with = "positive::serde::as_minor_units_option::<2>")]
+    maybe: Option<Positive>,
+    #[serde(with = "positive::serde::as_minor_units_vec::<4>")]
+    many: Vec<Positive>,
+}
+
+#[test]
+fn test_as_minor_units_roundtrip() {
+    let record = Cents {
+        value: Positive::new_decimal(dec!(12.34)).unwrap(),
+        maybe: Some(Positive::new_decimal(dec!(5)).unwrap()),
+        many: vec![
+            Positive::new_decimal(dec!(0.0001)).unwrap(),
+            Positive::new_decimal(dec!(1.5)).unwrap(),
+        ],
+    };
+    let json = serde_json::to_string(&record).unwrap();
+    assert_eq!(json, r#"{"value":1234,"maybe":500,"many":[1,15000]}"#);
+
+    let parsed: Cents = serde_json::from_str(&json).unwrap();
+    assert_eq!(parsed, record);
+    assert_eq!(parsed.value.into_inner().scale(), 2);
+}
+
+#[test]
+fn test_as_minor_units_rejects_extra_precision() {
+    let record = Cents {
+        value: Positive::new_decimal(dec!(12.345)).unwrap(),
+        maybe: None,
+        many: vec![],
+    };
+    let error = serde_json::to_string(&record).unwrap_err();
+    assert!(error.to_string().contains("more than 2 decimal places"));
+}
+
+#[test]
+fn test_as_minor_units_ignores_trailing_zeros() {
+    let record = Cents {
+        value: Positive::new_decimal(dec!(12.3000)).unwrap(),
+        maybe: None,
+        many: vec![],
+    };
+    let json = serde_json::to_string(&record).unwrap();
+    assert!(json.starts_with(r#"{"value":1230,"#));
+}
+
+#[test]
+fn test_as_minor_units_rejects_too_large() {
+    let record = Cents {
+        value: Positive::MAX,
+        maybe: None,
+        many: vec![],
+    };
+    assert!(serde_json::to_string(&record).is_err());
+}
+
+#[test]
+fn test_as_minor_units_rejects_negative_and_fractional() {
+    assert!(serde_json::from_str::<Cents>(r#"{"value":-1,"maybe":null,"many":[]}"#).is_err());
+    assert!(serde_json::from_str::<Cents>(r#"{"value":1.5,"maybe":null,"many":[]}"#).is_err());
+}
+
+// ============================================================================
+// as_parts
+// ============================================================================
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct Parts {
+    #[serde(with = "positive::serde::as_parts")]
+    value: Positive,
+    #[serde(with = "positive::serde::as_parts_option")]
+    maybe: Option<Positive>,
+    #[serde(with = "positive::serde::as_parts_vec")]
+    many: Vec<Positive>,
+}
+
+#[test]
+fn test_as_parts_roundtrip() {
+    let record = Parts {
+        value: Positive::new_decimal(dec!(123.4500)).unwrap(),
+        maybe: None,
+        many: vec![Positive::new_decimal(dec!(7)).unwrap()],
+    };
+    let json = serde_json::to_string(&record).unwrap();
+    assert_eq!(
+        json,
+        r#"{"value":{"mantissa":1234500,"scale":4},"maybe":null,"many":[{"mantissa":7,"scale":0}]}"#
+    );
+    let parsed: Parts = serde_json::from_str(&json).unwrap();
+    assert_eq!(
+        parsed.value.into_inner().serialize(),
+        dec!(123.4500).serialize()
+    );
+    assert_eq!(parsed, record);
+}
+
+#[test]
+fn test_as_parts_max_value() {
+    let record = Parts {
+        value: Positive::MAX,
+        maybe: Some(Positive::MAX),
+        many: vec![],
+    };
+    let json = serde_json::to_string(&record).unwrap();
+    assert_eq!(serde_json::from_str::<Parts>(&json).unwrap(), record);
+}
+
+#[test]
+fn test_as_parts_rejects_invalid_parts() {
+    // Scale above 28
+    let json = r#"{"value":{"mantissa":1,"scale":29},"maybe":null,"many":[]}"#;
+    assert!(serde_json::from_str::<Parts>(json).is_err());
+    // Mantissa above 96 bits
+    let json =
+        r#"{"value":{"mantissa":79228162514264337593543950336,"scale":0},"maybe":null,"many":[]}"#;
+    assert!(serde_json::from_str::<Parts>(json).is_err());
+    // Unknown field
+    let json = r#"{"value":{"mantissa":1,"scale":0,"sign":1},"maybe":null,"many":[]}"#;
+    assert!(serde_json::from_str::<Parts>(json).is_err());
+}
+
+// ============================================================================
+// Wide integers, f32 and arbitrary-precision numbers
+// ============================================================================
+
+mod extended_inputs {
+    use positive::{Positive, PositiveExt};
+    use rust_decimal::Decimal;
+    use rust_decimal_macros::dec;
+    use serde::Deserialize;
+    use serde::de::IntoDeserializer;
+    use serde::de::value::Error;
+
+    fn from_value<'de, T: IntoDeserializer<'de, Error>>(value: T) -> Result<Positive, Error> {
+        Positive::deserialize(value.into_deserializer())
+    }
+
+    fn from_json(json: &str) -> serde_json::Result<Positive> {
+        serde_json::from_str(json)
+    }
+
+    #[test]
+    fn test_i128_and_u128() {
+        let large: i128 = 10_i128.pow(24);
+        assert_eq!(
+            from_value(large).unwrap(),
+            Decimal::from_i128_with_scale(large, 0)
+        );
+        assert_eq!(
+            from_value(large as u128).unwrap(),
+            Decimal::from_i128_with_scale(large, 0)
+        );
+    }
+
+    #[test]
+    fn test_i128_and_u128_out_of_range() {
+        assert!(from_value(u128::MAX).is_err());
+        assert!(from_value(i128::MIN).is_err());
+        assert!(from_value(-1_i128).is_err());
+    }
+
+    #[test]
+    fn test_f32_keeps_its_own_precision() {
+        assert_eq!(from_value(0.1_f32).unwrap().into_inner(), dec!(0.1));
+        assert!(from_value(-0.5_f32).is_err());
+        assert!(from_value(f32::NAN).is_err());
+        assert!(from_value(f32::INFINITY).is_err());
+    }
+
+    #[test]
+    fn test_json_integer_beyond_u64_is_exact() {
+        // Without `arbitrary_precision` this arrives as an `f64`, whose
+        // nearest value is 99999999999999991611392.
+        assert_eq!(
+            from_json("100000000000000000000000").unwrap(),
+            dec!(100000000000000000000000)
+        );
+    }
+
+    #[test]
+    fn test_other_maps_are_rejected() {
+        assert!(from_json(r#"{"value":1.5}"#).is_err());
+    }
+
+    #[test]
+    fn test_json_numbers() {
+        assert_eq!(from_json("1.5e-3").unwrap(), dec!(0.0015));
+        assert_eq!(from_json("2E+5").unwrap(), dec!(200000));
+        assert_eq!(from_json("0.1").unwrap().into_inner(), dec!(0.1));
+        assert!(from_json("-1.5").is_err());
+        assert!(from_json("-1e2").is_err());
+    }
+
+    #[test]
+    #[cfg(not(feature = "arbitrary-precision-tests"))]
+    fn test_json_plain_numbers_go_through_f64() {
+        let value = from_json("0.1000000000000000000000000001").unwrap();
+        assert_eq!(value.into_inner(), dec!(0.1));
+        assert_eq!(from_json("123.4500").unwrap().into_inner().scale(), 2);
+    }
+
+    #[test]
+    #[cfg(feature = "arbitrary-precision-tests")]
+    fn test_json_arbitrary_precision_is_verbatim() {
+        let value = from_json("0.1000000000000000000000000001").unwrap();
+        assert_eq!(value.into_inner(), dec!(0.1000000000000000000000000001));
+        assert_eq!(from_json("123.4500").unwrap().into_inner().scale(), 4);
+    }
+
+    #[test]
+    fn test_positive_ext_json_numbers() {
+        let value: PositiveExt = serde_json::from_str("42.125").unwrap();
+        assert_eq!(value, Positive::new_decimal(dec!(42.125)).unwrap());
+        let value: PositiveExt = serde_json::from_str("100000000000000000000000").unwrap();
+        assert_eq!(
+            value,
+            Positive::new_decimal(dec!(100000000000000000000000)).unwrap()
+        );
+
+        let value: Result<PositiveExt, Error> =
+            PositiveExt::deserialize(f32::INFINITY.into_deserializer());
+        assert_eq!(value.unwrap(), PositiveExt::INFINITY);
+
+        let value: Result<PositiveExt, Error> =
+            PositiveExt::deserialize(7_u128.into_deserializer());
+        assert_eq!(value.unwrap(), Positive::new_decimal(dec!(7)).unwrap());
+    }
+}